@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::capture_mode::{ActiveRecording, CaptureMode};
+use crate::config::{CameraConfig, CameraFacing};
+
+/// Top-level camera app state: current mode, config, and (if recording)
+/// the in-progress video.
+pub struct CameraApp {
+    pub config: CameraConfig,
+    pub mode: CaptureMode,
+    pub facing: CameraFacing,
+    active_recording: Option<ActiveRecording>,
+}
+
+impl CameraApp {
+    pub fn new(config: CameraConfig) -> Self {
+        let facing = config.default_facing;
+        Self {
+            config,
+            mode: CaptureMode::Photo,
+            facing,
+            active_recording: None,
+        }
+    }
+
+    /// Switch between the front and rear camera. Not allowed mid-recording,
+    /// since the active `gstreamer` pipeline is bound to one video device.
+    pub fn switch_camera(&mut self) -> bool {
+        if self.active_recording.is_some() {
+            return false;
+        }
+        self.facing = match self.facing {
+            CameraFacing::Front => CameraFacing::Rear,
+            CameraFacing::Rear => CameraFacing::Front,
+        };
+        true
+    }
+
+    pub fn set_mode(&mut self, mode: CaptureMode) {
+        if self.active_recording.is_none() {
+            self.mode = mode;
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.active_recording.is_some()
+    }
+
+    pub fn recording_elapsed(&self) -> Option<Duration> {
+        self.active_recording.as_ref().map(ActiveRecording::elapsed)
+    }
+
+    /// Start recording video to a timestamped file under the configured
+    /// save location. No-op (returns the existing path) if already recording.
+    pub fn start_recording(&mut self) -> &PathBuf {
+        if self.active_recording.is_none() {
+            let output_path = self.config.save_location.join(next_video_filename());
+            self.active_recording = Some(ActiveRecording {
+                output_path,
+                started_at: Instant::now(),
+            });
+        }
+        &self.active_recording.as_ref().unwrap().output_path
+    }
+
+    /// Stop the in-progress recording and return its output path, if any.
+    pub fn stop_recording(&mut self) -> Option<PathBuf> {
+        self.active_recording.take().map(|r| r.output_path)
+    }
+
+    /// Path a freshly captured photo should be written to, under the
+    /// configured `save_location`.
+    pub fn next_photo_path(&self) -> PathBuf {
+        self.config.save_location.join(next_photo_filename())
+    }
+
+    /// Override the directory photos/videos are saved to for this session,
+    /// independent of what `settings.yml` configured at startup.
+    pub fn set_save_location(&mut self, path: PathBuf) {
+        self.config.save_location = path;
+    }
+}
+
+fn next_photo_filename() -> String {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("IMG_{timestamp}.jpg")
+}
+
+fn next_video_filename() -> String {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("VID_{timestamp}.mp4")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_recording_is_idempotent() {
+        let mut app = CameraApp::new(CameraConfig::default());
+        let first = app.start_recording().clone();
+        let second = app.start_recording().clone();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn switch_camera_toggles_facing() {
+        let mut app = CameraApp::new(CameraConfig::default());
+        assert_eq!(app.facing, CameraFacing::Rear);
+        assert!(app.switch_camera());
+        assert_eq!(app.facing, CameraFacing::Front);
+    }
+
+    #[test]
+    fn switch_camera_blocked_while_recording() {
+        let mut app = CameraApp::new(CameraConfig::default());
+        app.start_recording();
+        assert!(!app.switch_camera());
+    }
+
+    #[test]
+    fn stop_recording_clears_state() {
+        let mut app = CameraApp::new(CameraConfig::default());
+        app.start_recording();
+        assert!(app.is_recording());
+        let path = app.stop_recording();
+        assert!(path.is_some());
+        assert!(!app.is_recording());
+    }
+
+    #[test]
+    fn photo_path_uses_configured_save_location() {
+        let mut app = CameraApp::new(CameraConfig::default());
+        app.set_save_location(PathBuf::from("/mnt/sdcard/photos"));
+        assert!(app.next_photo_path().starts_with("/mnt/sdcard/photos"));
+    }
+}
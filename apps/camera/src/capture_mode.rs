@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Whether the camera app is currently taking photos or recording video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaptureMode {
+    #[default]
+    Photo,
+    Video,
+}
+
+/// State of an in-progress video recording.
+#[derive(Debug, Clone)]
+pub struct ActiveRecording {
+    pub output_path: PathBuf,
+    pub started_at: Instant,
+}
+
+impl ActiveRecording {
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.started_at.elapsed()
+    }
+}
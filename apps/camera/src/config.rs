@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Which physical camera a device with more than one exposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CameraFacing {
+    Front,
+    #[default]
+    Rear,
+}
+
+/// Camera app settings loaded from `settings.yml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CameraConfig {
+    /// Directory photos/videos are written to. Defaults to `~/Pictures`.
+    #[serde(default = "default_save_location")]
+    pub save_location: PathBuf,
+    #[serde(default)]
+    pub default_facing: CameraFacing,
+}
+
+impl Default for CameraConfig {
+    fn default() -> Self {
+        Self {
+            save_location: default_save_location(),
+            default_facing: CameraFacing::default(),
+        }
+    }
+}
+
+fn default_save_location() -> PathBuf {
+    dirs_pictures()
+}
+
+fn dirs_pictures() -> PathBuf {
+    std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/home"))
+        .join("Pictures")
+}
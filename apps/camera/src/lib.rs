@@ -0,0 +1,9 @@
+pub mod camera_app;
+pub mod capture_mode;
+pub mod config;
+pub mod settings_overlay;
+
+pub use camera_app::CameraApp;
+pub use capture_mode::{ActiveRecording, CaptureMode};
+pub use config::{CameraConfig, CameraFacing};
+pub use settings_overlay::{AspectRatio, OverlaySettings};
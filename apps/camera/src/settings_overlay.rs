@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// Supported framing aspect ratios for the viewfinder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum AspectRatio {
+    #[default]
+    Ratio4x3,
+    Ratio16x9,
+    Square,
+}
+
+impl AspectRatio {
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            AspectRatio::Ratio4x3 => 4.0 / 3.0,
+            AspectRatio::Ratio16x9 => 16.0 / 9.0,
+            AspectRatio::Square => 1.0,
+        }
+    }
+}
+
+/// Viewfinder overlay settings, rendered by the camera settings component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct OverlaySettings {
+    pub aspect_ratio: AspectRatio,
+    /// Rule-of-thirds grid lines drawn over the live preview.
+    #[serde(default)]
+    pub show_grid: bool,
+}
+
+impl OverlaySettings {
+    pub fn toggle_grid(&mut self) {
+        self.show_grid = !self.show_grid;
+    }
+
+    pub fn set_aspect_ratio(&mut self, ratio: AspectRatio) {
+        self.aspect_ratio = ratio;
+    }
+}
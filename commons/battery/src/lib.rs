@@ -0,0 +1,180 @@
+use std::time::{Duration, Instant};
+
+/// A single battery poll result, as reported by a `BatteryServiceHandle`
+/// (shared by the greeter and status bar, which each run their own).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatteryReading {
+    pub level: u8,
+    pub is_charging: bool,
+}
+
+/// Estimates time remaining until the battery reaches empty (discharging)
+/// or full (charging), from the rate of change between the two most
+/// recent readings. Resets its baseline whenever charging state flips, so
+/// a plug/unplug doesn't produce a wild estimate from stale data.
+#[derive(Debug, Default)]
+pub struct BatteryEstimator {
+    previous: Option<(BatteryReading, Instant)>,
+}
+
+impl BatteryEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a new reading and returns the estimated time remaining
+    /// until empty/full. Returns `None` until there are two comparable
+    /// readings, or the level hasn't actually moved since the last one
+    /// (which would make the rate undefined).
+    pub fn observe(&mut self, reading: BatteryReading, now: Instant) -> Option<Duration> {
+        let estimate = self.previous.and_then(|(previous, previous_at)| {
+            if previous.is_charging != reading.is_charging {
+                return None;
+            }
+            let elapsed = now.checked_duration_since(previous_at)?;
+            let delta = if reading.is_charging {
+                i32::from(reading.level) - i32::from(previous.level)
+            } else {
+                i32::from(previous.level) - i32::from(reading.level)
+            };
+            if delta <= 0 || elapsed.is_zero() {
+                return None;
+            }
+
+            let seconds_per_percent = elapsed.as_secs_f64() / f64::from(delta);
+            let remaining_percent = if reading.is_charging {
+                100u8.saturating_sub(reading.level)
+            } else {
+                reading.level
+            };
+            Some(Duration::from_secs_f64(seconds_per_percent * f64::from(remaining_percent)))
+        });
+        self.previous = Some((reading, now));
+        estimate
+    }
+}
+
+/// Debounces a "critical battery" warning so it fires once per crossing
+/// below `threshold` while not charging, rather than on every poll.
+/// Clears as soon as the device starts charging or recovers above the
+/// threshold, so the next crossing fires again.
+#[derive(Debug, Clone)]
+pub struct CriticalBatteryWarning {
+    threshold: u8,
+    is_active: bool,
+}
+
+impl CriticalBatteryWarning {
+    pub fn new(threshold: u8) -> Self {
+        Self {
+            threshold,
+            is_active: false,
+        }
+    }
+
+    /// Feeds a new reading. Returns `true` exactly once per crossing below
+    /// the threshold while not charging - callers use this to trigger the
+    /// transient warning overlay rather than re-showing it on every poll.
+    pub fn observe(&mut self, reading: BatteryReading) -> bool {
+        let is_critical = reading.level < self.threshold && !reading.is_charging;
+        if is_critical && !self.is_active {
+            self.is_active = true;
+            return true;
+        }
+        if !is_critical {
+            self.is_active = false;
+        }
+        false
+    }
+
+    /// Whether the warning is currently in its "triggered, not yet
+    /// recovered" state. The overlay itself may have already been
+    /// dismissed by the user; this just tracks the debounce window.
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+}
+
+impl Default for CriticalBatteryWarning {
+    /// 10%, the level most phone launchers warn at.
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(level: u8, is_charging: bool) -> BatteryReading {
+        BatteryReading { level, is_charging }
+    }
+
+    #[test]
+    fn fires_once_on_crossing_below_threshold() {
+        let mut warning = CriticalBatteryWarning::new(10);
+        assert!(!warning.observe(reading(50, false)));
+        assert!(warning.observe(reading(9, false)));
+        assert!(!warning.observe(reading(8, false)));
+        assert!(!warning.observe(reading(5, false)));
+    }
+
+    #[test]
+    fn charging_clears_and_allows_refire() {
+        let mut warning = CriticalBatteryWarning::new(10);
+        assert!(warning.observe(reading(9, false)));
+        assert!(!warning.observe(reading(9, true)));
+        assert!(!warning.is_active());
+        assert!(warning.observe(reading(9, false)));
+    }
+
+    #[test]
+    fn recovering_above_threshold_clears_and_allows_refire() {
+        let mut warning = CriticalBatteryWarning::new(10);
+        assert!(warning.observe(reading(9, false)));
+        assert!(!warning.observe(reading(50, false)));
+        assert!(warning.observe(reading(9, false)));
+    }
+
+    #[test]
+    fn single_reading_has_no_estimate_yet() {
+        let mut estimator = BatteryEstimator::new();
+        assert_eq!(estimator.observe(reading(50, false), Instant::now()), None);
+    }
+
+    #[test]
+    fn discharging_rate_projects_time_to_empty() {
+        let mut estimator = BatteryEstimator::new();
+        let start = Instant::now();
+        estimator.observe(reading(50, false), start);
+        // Dropped 10% in 100s -> 10s/percent, 40% remaining -> 400s to empty.
+        let estimate = estimator.observe(reading(40, false), start + Duration::from_secs(100));
+        assert_eq!(estimate, Some(Duration::from_secs(400)));
+    }
+
+    #[test]
+    fn charging_rate_projects_time_to_full() {
+        let mut estimator = BatteryEstimator::new();
+        let start = Instant::now();
+        estimator.observe(reading(50, true), start);
+        // Gained 10% in 50s -> 5s/percent, 40% remaining to full -> 200s.
+        let estimate = estimator.observe(reading(60, true), start + Duration::from_secs(50));
+        assert_eq!(estimate, Some(Duration::from_secs(200)));
+    }
+
+    #[test]
+    fn plugging_in_mid_discharge_resets_the_baseline() {
+        let mut estimator = BatteryEstimator::new();
+        let start = Instant::now();
+        estimator.observe(reading(50, false), start);
+        assert_eq!(estimator.observe(reading(50, true), start + Duration::from_secs(10)), None);
+    }
+
+    #[test]
+    fn unchanged_level_has_no_defined_rate() {
+        let mut estimator = BatteryEstimator::new();
+        let start = Instant::now();
+        estimator.observe(reading(50, false), start);
+        assert_eq!(estimator.observe(reading(50, false), start + Duration::from_secs(10)), None);
+    }
+}
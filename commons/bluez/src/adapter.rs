@@ -0,0 +1,23 @@
+use zbus::dbus_proxy;
+use zbus::zvariant::OwnedObjectPath;
+
+/// The local Bluetooth controller, `org.bluez.Adapter1`.
+#[dbus_proxy(interface = "org.bluez.Adapter1", default_service = "org.bluez")]
+trait Adapter {
+    fn start_discovery(&self) -> zbus::Result<()>;
+
+    fn stop_discovery(&self) -> zbus::Result<()>;
+
+    /// Forgets a paired device, by object path (e.g.
+    /// `/org/bluez/hci0/dev_AA_BB_CC_DD_EE_FF`).
+    fn remove_device(&self, device: &OwnedObjectPath) -> zbus::Result<()>;
+
+    #[dbus_proxy(property)]
+    fn powered(&self) -> zbus::Result<bool>;
+
+    #[dbus_proxy(property)]
+    fn set_powered(&self, value: bool) -> zbus::Result<()>;
+
+    #[dbus_proxy(property)]
+    fn discovering(&self) -> zbus::Result<bool>;
+}
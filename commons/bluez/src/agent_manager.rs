@@ -0,0 +1,20 @@
+use zbus::dbus_proxy;
+use zbus::zvariant::OwnedObjectPath;
+
+/// `org.bluez.AgentManager1`, used to register the pairing agent that
+/// answers PIN/passkey confirmation prompts during device pairing.
+#[dbus_proxy(
+    interface = "org.bluez.AgentManager1",
+    default_service = "org.bluez",
+    default_path = "/org/bluez"
+)]
+trait AgentManager {
+    /// `capability` is one of BlueZ's agent capability strings, e.g.
+    /// `"KeyboardDisplay"` for an agent that can show and confirm a
+    /// passkey.
+    fn register_agent(&self, agent: &OwnedObjectPath, capability: &str) -> zbus::Result<()>;
+
+    fn request_default_agent(&self, agent: &OwnedObjectPath) -> zbus::Result<()>;
+
+    fn unregister_agent(&self, agent: &OwnedObjectPath) -> zbus::Result<()>;
+}
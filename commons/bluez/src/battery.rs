@@ -0,0 +1,10 @@
+use zbus::dbus_proxy;
+
+/// `org.bluez.Battery1`, an optional interface BlueZ exposes at the same
+/// object path as `org.bluez.Device1` only when the device itself reports
+/// a battery level (earbuds, trackers, ...).
+#[dbus_proxy(interface = "org.bluez.Battery1", default_service = "org.bluez")]
+trait Battery {
+    #[dbus_proxy(property)]
+    fn percentage(&self) -> zbus::Result<u8>;
+}
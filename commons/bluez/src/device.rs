@@ -0,0 +1,67 @@
+use zbus::dbus_proxy;
+use zbus::zvariant::OwnedObjectPath;
+
+/// A Bluetooth device as reported by BlueZ, discovered or already paired.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceInfo {
+    pub address: String,
+    pub name: String,
+    pub paired: bool,
+    pub connected: bool,
+    pub trusted: bool,
+}
+
+#[dbus_proxy(interface = "org.bluez.Device1", default_service = "org.bluez")]
+trait Device {
+    fn pair(&self) -> zbus::Result<()>;
+
+    fn connect(&self) -> zbus::Result<()>;
+
+    fn disconnect(&self) -> zbus::Result<()>;
+
+    #[dbus_proxy(property)]
+    fn address(&self) -> zbus::Result<String>;
+
+    #[dbus_proxy(property)]
+    fn alias(&self) -> zbus::Result<String>;
+
+    /// A freedesktop icon name hint, e.g. `"audio-headset"`,
+    /// `"input-mouse"`. Not every device advertises one.
+    #[dbus_proxy(property)]
+    fn icon(&self) -> zbus::Result<String>;
+
+    #[dbus_proxy(property)]
+    fn paired(&self) -> zbus::Result<bool>;
+
+    #[dbus_proxy(property)]
+    fn connected(&self) -> zbus::Result<bool>;
+
+    #[dbus_proxy(property)]
+    fn trusted(&self) -> zbus::Result<bool>;
+
+    #[dbus_proxy(property)]
+    fn set_trusted(&self, value: bool) -> zbus::Result<()>;
+}
+
+/// Builds the BlueZ object path for `address` under `adapter_path`, e.g.
+/// `/org/bluez/hci0/dev_AA_BB_CC_DD_EE_FF` for `AA:BB:CC:DD:EE:FF` under
+/// `/org/bluez/hci0`. BlueZ always derives device paths this way rather
+/// than offering an address-to-path lookup call.
+pub fn device_path(adapter_path: &OwnedObjectPath, address: &str) -> zbus::Result<OwnedObjectPath> {
+    let suffix = address.replace(':', "_");
+    Ok(OwnedObjectPath::try_from(format!("{}/dev_{suffix}", adapter_path.as_str()))?)
+}
+
+impl<'a> DeviceProxy<'a> {
+    /// Fetches the device's properties in one shot and converts them into
+    /// the plain [`DeviceInfo`] the settings app deals with.
+    pub async fn info(&self) -> zbus::Result<DeviceInfo> {
+        Ok(DeviceInfo {
+            address: self.address().await?,
+            name: self.alias().await?,
+            paired: self.paired().await?,
+            connected: self.connected().await?,
+            trusted: self.trusted().await?,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+pub mod adapter;
+pub mod agent_manager;
+pub mod battery;
+pub mod device;
+pub mod object_manager;
+
+pub use adapter::AdapterProxy;
+pub use agent_manager::AgentManagerProxy;
+pub use battery::BatteryProxy;
+pub use device::{device_path, DeviceInfo, DeviceProxy};
+pub use object_manager::ObjectManagerProxy;
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use zbus::dbus_proxy;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue};
+
+type InterfacesAndProperties = HashMap<String, HashMap<String, OwnedValue>>;
+
+/// The root `org.freedesktop.DBus.ObjectManager` BlueZ exposes at `/`.
+/// Unlike NetworkManager's wireless device, BlueZ has no
+/// `GetAllDevices`-style call - devices only show up as children of their
+/// adapter in this object tree.
+#[dbus_proxy(
+    interface = "org.freedesktop.DBus.ObjectManager",
+    default_service = "org.bluez",
+    default_path = "/"
+)]
+trait ObjectManager {
+    fn get_managed_objects(&self) -> zbus::Result<HashMap<OwnedObjectPath, InterfacesAndProperties>>;
+}
+
+impl<'a> ObjectManagerProxy<'a> {
+    /// Object paths implementing `org.bluez.Device1` that sit under
+    /// `adapter_path`, e.g. `/org/bluez/hci0/dev_AA_BB_CC_DD_EE_FF` under
+    /// `/org/bluez/hci0`.
+    pub async fn device_paths_under(&self, adapter_path: &str) -> zbus::Result<Vec<OwnedObjectPath>> {
+        let objects = self.get_managed_objects().await?;
+        Ok(objects
+            .into_iter()
+            .filter(|(path, interfaces)| {
+                path.as_str().starts_with(adapter_path) && interfaces.contains_key("org.bluez.Device1")
+            })
+            .map(|(path, _)| path)
+            .collect())
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+/// A clock display setting, as configured in `settings.yml`'s
+/// `clock_format`. Maps to the strftime string [`ClockServiceHandle`]
+/// actually renders with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Clock {
+    TwelveHour,
+    #[default]
+    TwentyFourHour,
+    Custom(String),
+}
+
+impl Clock {
+    pub fn format_string(&self) -> &str {
+        match self {
+            Clock::TwelveHour => "%I:%M %p",
+            Clock::TwentyFourHour => "%H:%M",
+            Clock::Custom(format) => format,
+        }
+    }
+
+    /// Whether this format renders seconds, and so needs second-resolution
+    /// ticking to stay accurate.
+    pub fn has_seconds(&self) -> bool {
+        self.format_string().contains("%S")
+    }
+}
+
+/// Parses an IANA timezone name (e.g. `"America/New_York"`) as configured
+/// in `settings.yml`'s `timezone` field, falling back to UTC for an empty
+/// or unrecognized name rather than failing settings load outright.
+pub fn parse_timezone(name: &str) -> Tz {
+    name.parse().unwrap_or(Tz::UTC)
+}
+
+/// How often the tick loop needs to wake for a format to stay accurate:
+/// every second if it renders seconds, otherwise once a minute is plenty -
+/// no reason to wake every second just to re-render the same `HH:MM`.
+fn tick_interval_for(format: &Clock) -> Duration {
+    if format.has_seconds() {
+        Duration::from_secs(1)
+    } else {
+        Duration::from_secs(60)
+    }
+}
+
+fn render(format: &Clock, timezone: Tz) -> String {
+    Utc::now().with_timezone(&timezone).format(format.format_string()).to_string()
+}
+
+/// Runs the background tick loop and hands out formatted time strings,
+/// with the format and timezone changeable live (no restart needed when
+/// `settings.yml` changes). Both the greeter and status bar run their own.
+pub struct ClockServiceHandle {
+    config_tx: watch::Sender<(Clock, Tz)>,
+    time_rx: watch::Receiver<String>,
+}
+
+impl ClockServiceHandle {
+    pub fn run(format: Clock, timezone: Tz) -> Self {
+        let rendered = render(&format, timezone);
+        let (config_tx, config_rx) = watch::channel((format, timezone));
+        let (time_tx, time_rx) = watch::channel(rendered);
+        tokio::spawn(tick_loop(config_rx, time_tx));
+        Self { config_tx, time_rx }
+    }
+
+    /// Updates the format live. The tick loop emits a fresh render
+    /// immediately rather than waiting out the rest of the current
+    /// interval, so the change is visible right away.
+    pub fn set_format(&self, format: Clock) {
+        let timezone = self.config_tx.borrow().1;
+        let _ = self.config_tx.send((format, timezone));
+    }
+
+    /// Updates the timezone live, with the same immediate-tick behavior as
+    /// [`Self::set_format`].
+    pub fn set_timezone(&self, timezone: Tz) {
+        let format = self.config_tx.borrow().0.clone();
+        let _ = self.config_tx.send((format, timezone));
+    }
+
+    /// Waits for the next rendered tick (a scheduled tick, or an
+    /// immediate one from a format/timezone change).
+    pub async fn changed(&mut self) -> Option<String> {
+        self.time_rx.changed().await.ok()?;
+        Some(self.time_rx.borrow().clone())
+    }
+
+    pub fn current(&self) -> String {
+        self.time_rx.borrow().clone()
+    }
+}
+
+async fn tick_loop(mut config_rx: watch::Receiver<(Clock, Tz)>, time_tx: watch::Sender<String>) {
+    loop {
+        let (format, timezone) = config_rx.borrow().clone();
+        let _ = time_tx.send(render(&format, timezone));
+
+        tokio::select! {
+            _ = tokio::time::sleep(tick_interval_for(&format)) => {}
+            changed = config_rx.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twelve_hour_maps_to_strftime_with_meridiem() {
+        assert_eq!(Clock::TwelveHour.format_string(), "%I:%M %p");
+    }
+
+    #[test]
+    fn twenty_four_hour_maps_to_strftime_without_meridiem() {
+        assert_eq!(Clock::TwentyFourHour.format_string(), "%H:%M");
+    }
+
+    #[test]
+    fn custom_format_is_passed_through_verbatim() {
+        assert_eq!(Clock::Custom("%H:%M:%S".to_string()).format_string(), "%H:%M:%S");
+    }
+
+    #[test]
+    fn format_with_seconds_selects_one_second_tick() {
+        assert_eq!(
+            tick_interval_for(&Clock::Custom("%H:%M:%S".to_string())),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn format_without_seconds_selects_one_minute_tick() {
+        assert_eq!(tick_interval_for(&Clock::TwentyFourHour), Duration::from_secs(60));
+        assert_eq!(tick_interval_for(&Clock::TwelveHour), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn unrecognized_timezone_name_falls_back_to_utc() {
+        assert_eq!(parse_timezone("not-a-real-timezone"), Tz::UTC);
+    }
+
+    #[test]
+    fn valid_timezone_name_parses() {
+        assert_eq!(parse_timezone("America/New_York"), Tz::America__New_York);
+    }
+}
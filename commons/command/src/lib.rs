@@ -0,0 +1,177 @@
+use std::fmt;
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+/// Error raised by [`execute_command`]/[`execute_command_async`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The command couldn't even be spawned (not found, no permission, ...).
+    Spawn(String),
+    /// The command ran but exited with a non-zero status. `exit_code` is
+    /// `None` if the process was killed by a signal instead of exiting, so
+    /// a caller can still branch on specific codes (e.g. a supplicant
+    /// returning exit 1 for "wrong password" vs. exit 2 for "device busy").
+    NonZeroExit { exit_code: Option<i32>, stderr: String },
+    /// The command's stdout wasn't valid UTF-8.
+    InvalidUtf8(String),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Spawn(reason) => write!(f, "failed to spawn command: {reason}"),
+            CommandError::NonZeroExit { exit_code: Some(code), stderr } => {
+                write!(f, "command exited with status {code}: {stderr}")
+            }
+            CommandError::NonZeroExit { exit_code: None, stderr } => {
+                write!(f, "command was terminated by a signal: {stderr}")
+            }
+            CommandError::InvalidUtf8(reason) => write!(f, "command output was not valid UTF-8: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Builds a [`CommandError::NonZeroExit`] from a failed [`std::process::Output`].
+fn non_zero_exit(output: &std::process::Output) -> CommandError {
+    CommandError::NonZeroExit {
+        exit_code: output.status.code(),
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    }
+}
+
+/// Runs `command` with `args` and returns its trimmed stdout, blocking the
+/// calling thread for the duration of the process.
+pub fn execute_command(command: &str, args: &[&str]) -> Result<String, CommandError> {
+    let output = Command::new(command)
+        .args(args)
+        .output()
+        .map_err(|err| CommandError::Spawn(err.to_string()))?;
+
+    if !output.status.success() {
+        return Err(non_zero_exit(&output));
+    }
+
+    String::from_utf8(output.stdout)
+        .map(|stdout| stdout.trim().to_string())
+        .map_err(|err| CommandError::InvalidUtf8(err.to_string()))
+}
+
+/// Runs [`execute_command`], retrying up to `attempts` times with linear
+/// backoff (`backoff`, `2 * backoff`, `3 * backoff`, ...) on transient
+/// failures. Doesn't retry [`CommandError::Spawn`] - a missing or
+/// unrunnable binary won't fix itself between attempts. Returns the last
+/// error if every attempt fails.
+pub fn execute_command_retry(
+    command: &str,
+    args: &[&str],
+    attempts: u32,
+    backoff: Duration,
+) -> Result<String, CommandError> {
+    let attempts = attempts.max(1);
+    let mut last_err = None;
+
+    for attempt in 0..attempts {
+        match execute_command(command, args) {
+            Ok(output) => return Ok(output),
+            Err(err @ CommandError::Spawn(_)) => return Err(err),
+            Err(err) => {
+                last_err = Some(err);
+                if attempt + 1 < attempts {
+                    thread::sleep(backoff * (attempt + 1));
+                }
+            }
+        }
+    }
+
+    Err(last_err.expect("the loop runs at least once, so a failure always sets last_err"))
+}
+
+#[cfg(feature = "tokio")]
+/// Async equivalent of [`execute_command`] for tokio-based services, so a
+/// caller doesn't have to block its runtime or spawn a thread just to run
+/// a process. Mirrors the same [`CommandError`] variants.
+pub async fn execute_command_async(command: &str, args: &[&str]) -> Result<String, CommandError> {
+    let output = tokio::process::Command::new(command)
+        .args(args)
+        .output()
+        .await
+        .map_err(|err| CommandError::Spawn(err.to_string()))?;
+
+    if !output.status.success() {
+        return Err(non_zero_exit(&output));
+    }
+
+    String::from_utf8(output.stdout)
+        .map(|stdout| stdout.trim().to_string())
+        .map_err(|err| CommandError::InvalidUtf8(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_trimmed_stdout() {
+        assert_eq!(execute_command("echo", &["hello"]).unwrap(), "hello");
+    }
+
+    #[test]
+    fn missing_command_is_a_spawn_error() {
+        assert!(matches!(
+            execute_command("definitely-not-a-real-command", &[]),
+            Err(CommandError::Spawn(_))
+        ));
+    }
+
+    #[test]
+    fn non_zero_exit_is_reported() {
+        assert!(matches!(
+            execute_command("false", &[]),
+            Err(CommandError::NonZeroExit { .. })
+        ));
+    }
+
+    #[test]
+    fn non_zero_exit_carries_the_exit_code_and_stderr() {
+        match execute_command("sh", &["-c", "echo oops >&2; exit 2"]) {
+            Err(CommandError::NonZeroExit { exit_code, stderr }) => {
+                assert_eq!(exit_code, Some(2));
+                assert_eq!(stderr, "oops");
+            }
+            other => panic!("expected NonZeroExit, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn async_variant_returns_trimmed_stdout() {
+        assert_eq!(execute_command_async("echo", &["hello"]).await.unwrap(), "hello");
+    }
+
+    #[test]
+    fn retry_succeeds_on_a_working_command() {
+        assert_eq!(
+            execute_command_retry("echo", &["hello"], 3, Duration::from_millis(1)).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn retry_gives_up_and_returns_the_last_error_on_a_persistent_failure() {
+        assert!(matches!(
+            execute_command_retry("false", &[], 3, Duration::from_millis(1)),
+            Err(CommandError::NonZeroExit { .. })
+        ));
+    }
+
+    #[test]
+    fn retry_does_not_retry_a_missing_binary() {
+        assert!(matches!(
+            execute_command_retry("definitely-not-a-real-command", &[], 3, Duration::from_millis(1)),
+            Err(CommandError::Spawn(_))
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+/// Search order for a named config file, shared by every shell surface
+/// that reads its own settings from disk: an explicit override via
+/// `MECHA_SETTINGS_PATH`, then the user config dir, then the system-wide
+/// config. Several crates had grown their own slightly different version
+/// of this; they should all call this instead.
+pub fn find_config_path(file_name: &str) -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("MECHA_SETTINGS_PATH") {
+        return Some(PathBuf::from(path));
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        let candidate = PathBuf::from(home).join(".config/mechanix").join(file_name);
+        if candidate.exists() {
+            return Some(candidate);
+        }
+    }
+    let system = PathBuf::from("/etc/mechanix").join(file_name);
+    system.exists().then_some(system)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_override_wins_over_user_and_system_paths() {
+        std::env::set_var("MECHA_SETTINGS_PATH", "/tmp/mechanix-test-override.yml");
+        assert_eq!(
+            find_config_path("settings.yml"),
+            Some(PathBuf::from("/tmp/mechanix-test-override.yml"))
+        );
+        std::env::remove_var("MECHA_SETTINGS_PATH");
+    }
+
+    #[test]
+    fn missing_file_returns_none() {
+        std::env::remove_var("MECHA_SETTINGS_PATH");
+        std::env::set_var("HOME", "/nonexistent-mechanix-test-home");
+        assert_eq!(find_config_path("does-not-exist.yml"), None);
+    }
+}
@@ -0,0 +1,142 @@
+use std::fs;
+use std::path::Path;
+
+pub mod matching;
+
+pub use matching::{format_apps_from_map_to_vec, match_desktop_entry, AppTile, DEFAULT_ICON};
+
+const APPLICATIONS_DIR: &str = "/usr/share/applications";
+
+/// A parsed `.desktop` file, as used to populate the homescreen/launcher
+/// app lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesktopEntry {
+    pub app_id: String,
+    pub name: String,
+    pub exec: String,
+    pub icon: Option<String>,
+    pub no_display: bool,
+    pub hidden: bool,
+    /// Hints which toplevel `app_id`/WM_CLASS this entry belongs to; the
+    /// most reliable thing to match a compositor-reported app_id against,
+    /// since `Icon=`/`Exec=` often don't agree with it at all.
+    pub startup_wm_class: Option<String>,
+    /// `Categories=` split on `;`, empty when the entry doesn't declare any.
+    pub categories: Vec<String>,
+}
+
+impl DesktopEntry {
+    /// Entries with `NoDisplay=true` or `Hidden=true` are valid desktop
+    /// entries that should never appear in a menu/launcher UI (per the
+    /// freedesktop.org Desktop Entry Specification).
+    pub fn should_display(&self) -> bool {
+        !self.no_display && !self.hidden
+    }
+}
+
+/// Scans `/usr/share/applications` for `.desktop` files and returns the
+/// ones that should be shown in app lists (see [`DesktopEntry::should_display`]).
+pub fn discover_apps() -> Vec<DesktopEntry> {
+    discover_apps_in(Path::new(APPLICATIONS_DIR))
+}
+
+fn discover_apps_in(dir: &Path) -> Vec<DesktopEntry> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("desktop"))
+        .filter_map(|entry| {
+            let app_id = entry.path().file_stem()?.to_str()?.to_string();
+            let contents = fs::read_to_string(entry.path()).ok()?;
+            parse_desktop_entry(&app_id, &contents)
+        })
+        .filter(DesktopEntry::should_display)
+        .collect()
+}
+
+fn parse_desktop_entry(app_id: &str, contents: &str) -> Option<DesktopEntry> {
+    let mut name = None;
+    let mut exec = None;
+    let mut icon = None;
+    let mut no_display = false;
+    let mut hidden = false;
+    let mut startup_wm_class = None;
+    let mut categories = Vec::new();
+
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("Name=") {
+            name = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("Exec=") {
+            exec = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("Icon=") {
+            icon = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("NoDisplay=") {
+            no_display = value.trim().eq_ignore_ascii_case("true");
+        } else if let Some(value) = line.strip_prefix("Hidden=") {
+            hidden = value.trim().eq_ignore_ascii_case("true");
+        } else if let Some(value) = line.strip_prefix("StartupWMClass=") {
+            startup_wm_class = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("Categories=") {
+            categories = value
+                .trim()
+                .split(';')
+                .map(str::trim)
+                .filter(|category| !category.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+    }
+
+    Some(DesktopEntry {
+        app_id: app_id.to_string(),
+        name: name?,
+        exec: exec?,
+        icon,
+        no_display,
+        hidden,
+        startup_wm_class,
+        categories,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodisplay_entry_is_filtered() {
+        let entry = parse_desktop_entry(
+            "foo",
+            "Name=Foo\nExec=foo\nNoDisplay=true\n",
+        )
+        .unwrap();
+        assert!(!entry.should_display());
+    }
+
+    #[test]
+    fn hidden_entry_is_filtered() {
+        let entry = parse_desktop_entry("foo", "Name=Foo\nExec=foo\nHidden=true\n").unwrap();
+        assert!(!entry.should_display());
+    }
+
+    #[test]
+    fn normal_entry_is_displayed() {
+        let entry = parse_desktop_entry("foo", "Name=Foo\nExec=foo\n").unwrap();
+        assert!(entry.should_display());
+    }
+
+    #[test]
+    fn categories_are_split_and_trimmed() {
+        let entry = parse_desktop_entry("foo", "Name=Foo\nExec=foo\nCategories=Network;WebBrowser;\n").unwrap();
+        assert_eq!(entry.categories, vec!["Network".to_string(), "WebBrowser".to_string()]);
+    }
+
+    #[test]
+    fn missing_categories_is_empty() {
+        let entry = parse_desktop_entry("foo", "Name=Foo\nExec=foo\n").unwrap();
+        assert!(entry.categories.is_empty());
+    }
+}
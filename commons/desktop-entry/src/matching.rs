@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::DesktopEntry;
+
+/// Shown when no desktop entry can be matched to a toplevel's `app_id`, so
+/// the grid never renders a blank tile.
+pub const DEFAULT_ICON: &str = "application-x-executable";
+
+/// A toplevel window's `app_id` resolved against the installed desktop
+/// entries, ready to render as a grid/dock tile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppTile {
+    pub app_id: String,
+    pub name: String,
+    pub icon: String,
+    pub window_count: u32,
+}
+
+/// Matches a compositor-reported toplevel `app_id` to the desktop entry it
+/// most likely corresponds to. Tried most specific first, since
+/// `Icon=`/`Exec=` frequently disagree with the actual app_id
+/// (e.g. `org.gnome.Nautilus` vs `nautilus`):
+///
+/// 1. `StartupWMClass` exact match - the spec's intended mechanism for this.
+/// 2. `Icon=` exact match.
+/// 3. `Exec=` containing the app_id as a substring.
+/// 4. Case-insensitive compare against the desktop file's basename.
+pub fn match_desktop_entry<'a>(
+    app_id: &str,
+    entries: &'a [DesktopEntry],
+) -> Option<&'a DesktopEntry> {
+    entries
+        .iter()
+        .find(|entry| entry.startup_wm_class.as_deref() == Some(app_id))
+        .or_else(|| entries.iter().find(|entry| entry.icon.as_deref() == Some(app_id)))
+        .or_else(|| entries.iter().find(|entry| entry.exec.contains(app_id)))
+        .or_else(|| entries.iter().find(|entry| entry.app_id.eq_ignore_ascii_case(app_id)))
+}
+
+/// Builds the tiles the homescreen/app-dock render from a map of running
+/// toplevels (`app_id` -> window count) and the installed desktop entries.
+pub fn format_apps_from_map_to_vec(
+    toplevels: &HashMap<String, u32>,
+    entries: &[DesktopEntry],
+) -> Vec<AppTile> {
+    toplevels
+        .iter()
+        .map(|(app_id, &window_count)| match match_desktop_entry(app_id, entries) {
+            Some(entry) => AppTile {
+                app_id: app_id.clone(),
+                name: entry.name.clone(),
+                icon: entry.icon.clone().unwrap_or_else(|| DEFAULT_ICON.to_string()),
+                window_count,
+            },
+            None => AppTile {
+                app_id: app_id.clone(),
+                name: app_id.clone(),
+                icon: DEFAULT_ICON.to_string(),
+                window_count,
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(app_id: &str, icon: Option<&str>, exec: &str, wm_class: Option<&str>) -> DesktopEntry {
+        DesktopEntry {
+            app_id: app_id.to_string(),
+            name: app_id.to_string(),
+            exec: exec.to_string(),
+            icon: icon.map(str::to_string),
+            no_display: false,
+            hidden: false,
+            startup_wm_class: wm_class.map(str::to_string),
+            categories: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn prefers_startup_wm_class_over_icon_and_exec() {
+        let entries = vec![entry(
+            "org.gnome.Nautilus",
+            Some("nautilus"),
+            "/usr/bin/nautilus %U",
+            Some("org.gnome.Nautilus"),
+        )];
+        let matched = match_desktop_entry("org.gnome.Nautilus", &entries).unwrap();
+        assert_eq!(matched.app_id, "org.gnome.Nautilus");
+    }
+
+    #[test]
+    fn falls_back_to_exec_substring_match() {
+        let entries = vec![entry("nautilus", None, "/usr/bin/nautilus %U", None)];
+        let matched = match_desktop_entry("nautilus", &entries).unwrap();
+        assert_eq!(matched.exec, "/usr/bin/nautilus %U");
+    }
+
+    #[test]
+    fn unmatched_app_id_gets_default_icon_not_none() {
+        let tiles = format_apps_from_map_to_vec(
+            &HashMap::from([("mystery.app".to_string(), 1)]),
+            &[],
+        );
+        assert_eq!(tiles[0].icon, DEFAULT_ICON);
+        assert_eq!(tiles[0].name, "mystery.app");
+    }
+}
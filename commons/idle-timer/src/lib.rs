@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// A reusable "fire after N seconds of no activity, unless reset" timer.
+///
+/// Used by any shell surface (greeter, shell event loop, lock screen, ...)
+/// that needs to react to user inactivity. Construct with `duration: None`
+/// to disable it entirely - `wait_for_idle` then never resolves.
+pub struct IdleTimer {
+    reset_tx: watch::Sender<()>,
+}
+
+pub struct IdleTimerHandle {
+    duration: Option<Duration>,
+    reset_rx: watch::Receiver<()>,
+}
+
+impl IdleTimer {
+    pub fn new(duration: Option<Duration>) -> (Self, IdleTimerHandle) {
+        let (reset_tx, reset_rx) = watch::channel(());
+        (Self { reset_tx }, IdleTimerHandle { duration, reset_rx })
+    }
+
+    /// Call on any user input event to push the deadline back out.
+    pub fn reset(&self) {
+        let _ = self.reset_tx.send(());
+    }
+}
+
+impl IdleTimerHandle {
+    /// Resolves once `duration` has elapsed with no intervening `reset()`
+    /// call. Never resolves if the timer was constructed with `None`.
+    pub async fn wait_for_idle(&mut self) {
+        let Some(duration) = self.duration else {
+            std::future::pending::<()>().await;
+            return;
+        };
+
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(duration) => return,
+                changed = self.reset_rx.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
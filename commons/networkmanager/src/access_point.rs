@@ -0,0 +1,43 @@
+use zbus::dbus_proxy;
+
+/// A Wi-Fi access point as reported by NetworkManager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessPointInfo {
+    pub ssid: String,
+    /// 0-100.
+    pub strength: u8,
+    pub is_secured: bool,
+}
+
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager.AccessPoint",
+    default_service = "org.freedesktop.NetworkManager"
+)]
+trait AccessPoint {
+    #[dbus_proxy(property)]
+    fn ssid(&self) -> zbus::Result<Vec<u8>>;
+
+    #[dbus_proxy(property)]
+    fn strength(&self) -> zbus::Result<u8>;
+
+    #[dbus_proxy(property)]
+    fn wpa_flags(&self) -> zbus::Result<u32>;
+
+    #[dbus_proxy(property)]
+    fn rsn_flags(&self) -> zbus::Result<u32>;
+}
+
+impl<'a> AccessPointProxy<'a> {
+    /// Fetch the access point's properties in one shot and convert them
+    /// into the plain [`AccessPointInfo`] the settings app UI deals with.
+    pub async fn info(&self) -> zbus::Result<AccessPointInfo> {
+        let ssid = String::from_utf8_lossy(&self.ssid().await?).to_string();
+        let strength = self.strength().await?;
+        let is_secured = self.wpa_flags().await? != 0 || self.rsn_flags().await? != 0;
+        Ok(AccessPointInfo {
+            ssid,
+            strength,
+            is_secured,
+        })
+    }
+}
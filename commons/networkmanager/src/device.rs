@@ -0,0 +1,17 @@
+use zbus::dbus_proxy;
+use zbus::zvariant::OwnedObjectPath;
+
+/// The base `org.freedesktop.NetworkManager.Device` interface, common to
+/// every device type (wired, wireless, ...).
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager.Device",
+    default_service = "org.freedesktop.NetworkManager"
+)]
+trait Device {
+    /// An `NM_DEVICE_STATE_*` value, e.g. 100 = activated.
+    #[dbus_proxy(property)]
+    fn state(&self) -> zbus::Result<u32>;
+
+    #[dbus_proxy(property)]
+    fn ip4_config(&self) -> zbus::Result<OwnedObjectPath>;
+}
@@ -0,0 +1,38 @@
+use zbus::dbus_proxy;
+
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager.IP4Config",
+    default_service = "org.freedesktop.NetworkManager"
+)]
+trait IP4Config {
+    /// Array of `(address, prefix, gateway)` tuples, each a big-endian
+    /// u32, one per address assigned to the device. This is the deprecated
+    /// NetworkManager property, but it's what ships on the bookworm-based
+    /// images this runs on.
+    #[dbus_proxy(property)]
+    fn addresses(&self) -> zbus::Result<Vec<(u32, u32, u32)>>;
+
+    #[dbus_proxy(property)]
+    fn gateway(&self) -> zbus::Result<String>;
+}
+
+/// Format a NetworkManager `IP4Config.Addresses` entry (a big-endian u32)
+/// as a dotted-quad string, e.g. `192.168.1.42`.
+pub fn format_ipv4(address: u32) -> String {
+    let octets = address.to_be_bytes();
+    format!(
+        "{}.{}.{}.{}",
+        octets[0], octets[1], octets[2], octets[3]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_dotted_quad() {
+        let address = u32::from_be_bytes([192, 168, 1, 42]);
+        assert_eq!(format_ipv4(address), "192.168.1.42");
+    }
+}
@@ -0,0 +1,18 @@
+use zbus::dbus_proxy;
+use zbus::zvariant::OwnedValue;
+use std::collections::HashMap;
+
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager.IP6Config",
+    default_service = "org.freedesktop.NetworkManager"
+)]
+trait IP6Config {
+    /// Array of `{address, prefix}` dictionaries. Unlike `IP4Config`,
+    /// NetworkManager never shipped a deprecated integer-tuple form of
+    /// this property, so we go straight to `AddressData`.
+    #[dbus_proxy(property)]
+    fn address_data(&self) -> zbus::Result<Vec<HashMap<String, OwnedValue>>>;
+
+    #[dbus_proxy(property)]
+    fn gateway(&self) -> zbus::Result<String>;
+}
@@ -0,0 +1,15 @@
+pub mod access_point;
+pub mod device;
+pub mod ip4_config;
+pub mod ip6_config;
+pub mod manager;
+pub mod wired_device;
+pub mod wireless_device;
+
+pub use access_point::{AccessPointInfo, AccessPointProxy};
+pub use device::DeviceProxy;
+pub use ip4_config::{format_ipv4, IP4ConfigProxy};
+pub use ip6_config::IP6ConfigProxy;
+pub use manager::NetworkManagerProxy;
+pub use wired_device::WiredDeviceProxy;
+pub use wireless_device::WirelessDeviceProxy;
@@ -0,0 +1,20 @@
+use zbus::dbus_proxy;
+
+/// The root `org.freedesktop.NetworkManager` object other devices and
+/// access points hang off of.
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager",
+    default_service = "org.freedesktop.NetworkManager",
+    default_path = "/org/freedesktop/NetworkManager"
+)]
+trait NetworkManager {
+    /// Whether the Wi-Fi radio is enabled at the NetworkManager level,
+    /// independent of rfkill/airplane mode (`WirelessHardwareEnabled`,
+    /// read-only and not exposed here since this crate has no use for it
+    /// yet).
+    #[dbus_proxy(property)]
+    fn wireless_enabled(&self) -> zbus::Result<bool>;
+
+    #[dbus_proxy(property)]
+    fn set_wireless_enabled(&self, value: bool) -> zbus::Result<()>;
+}
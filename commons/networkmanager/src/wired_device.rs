@@ -0,0 +1,10 @@
+use zbus::dbus_proxy;
+
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager.Device.Wired",
+    default_service = "org.freedesktop.NetworkManager"
+)]
+trait WiredDevice {
+    #[dbus_proxy(property)]
+    fn carrier(&self) -> zbus::Result<bool>;
+}
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use zbus::dbus_proxy;
+use zbus::zvariant::OwnedObjectPath;
+
+#[dbus_proxy(
+    interface = "org.freedesktop.NetworkManager.Device.Wireless",
+    default_service = "org.freedesktop.NetworkManager"
+)]
+trait WirelessDevice {
+    /// Ask NetworkManager to rescan for access points. Returns once the
+    /// scan request is accepted, not once it finishes - wait for
+    /// `LastScan` to move, or just re-call `GetAllAccessPoints` after a
+    /// short delay as the settings app does.
+    fn request_scan(&self, options: HashMap<&str, zbus::zvariant::Value<'_>>) -> zbus::Result<()>;
+
+    fn get_all_access_points(&self) -> zbus::Result<Vec<OwnedObjectPath>>;
+
+    #[dbus_proxy(property)]
+    fn active_access_point(&self) -> zbus::Result<OwnedObjectPath>;
+
+    #[dbus_proxy(property)]
+    fn last_scan(&self) -> zbus::Result<i64>;
+}
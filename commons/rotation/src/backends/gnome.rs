@@ -0,0 +1,115 @@
+use zbus::blocking::Connection;
+use zbus::zvariant::Value;
+
+use crate::{DisplayManager, Orientation, RotationError};
+
+/// The `org.gnome.Mutter.DisplayConfig` transform values, as documented by
+/// mutter - not the same numbering as wlroots' output transform enum.
+const TRANSFORM_NORMAL: u32 = 0;
+const TRANSFORM_90: u32 = 1;
+const TRANSFORM_180: u32 = 2;
+const TRANSFORM_270: u32 = 3;
+
+/// One entry of `GetCurrentState`'s `logical_monitors` array: `(x, y,
+/// scale, transform, is_primary, monitors)`.
+type LogicalMonitor = (i32, i32, f64, u32, bool, Vec<(String, String, Vec<zbus::zvariant::OwnedValue>)>);
+
+/// `org.gnome.Mutter.DisplayConfig`'s `GetCurrentState` reply: `(serial,
+/// monitors, logical_monitors, properties)`.
+type DisplayConfigState = (
+    u32,
+    Vec<zbus::zvariant::OwnedValue>,
+    Vec<LogicalMonitor>,
+    std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+);
+
+/// Rotates the display on GNOME/mutter via `org.gnome.Mutter.DisplayConfig`.
+pub struct GnomeBackend;
+
+impl GnomeBackend {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn transform_for(orientation: Orientation) -> u32 {
+        match orientation {
+            Orientation::Normal => TRANSFORM_NORMAL,
+            Orientation::Left => TRANSFORM_90,
+            Orientation::Right => TRANSFORM_270,
+            Orientation::UpsideDown => TRANSFORM_180,
+        }
+    }
+
+    fn orientation_for(transform: u32) -> Orientation {
+        match transform {
+            TRANSFORM_90 => Orientation::Left,
+            TRANSFORM_180 => Orientation::UpsideDown,
+            TRANSFORM_270 => Orientation::Right,
+            _ => Orientation::Normal,
+        }
+    }
+
+    fn proxy(connection: &Connection) -> Result<zbus::blocking::Proxy<'_>, RotationError> {
+        zbus::blocking::Proxy::new(
+            connection,
+            "org.gnome.Mutter.DisplayConfig",
+            "/org/gnome/Mutter/DisplayConfig",
+            "org.gnome.Mutter.DisplayConfig",
+        )
+        .map_err(|err| RotationError::BackendError(err.to_string()))
+    }
+}
+
+impl Default for GnomeBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayManager for GnomeBackend {
+    fn change_rotation_state(&self, orientation: Orientation) -> Result<(), RotationError> {
+        let connection =
+            Connection::session().map_err(|err| RotationError::BackendError(err.to_string()))?;
+        let proxy = Self::proxy(&connection)?;
+
+        // GetCurrentState returns (serial, monitors, logical_monitors, properties).
+        // We only need the serial and each logical monitor's geometry to
+        // build a minimal ApplyMonitorsConfig call that just changes the transform.
+        let (serial, _monitors, logical_monitors, _properties): DisplayConfigState = proxy
+            .call("GetCurrentState", &())
+            .map_err(|err| RotationError::BackendError(err.to_string()))?;
+
+        let transform = Self::transform_for(orientation);
+        let logical_monitors: Vec<_> = logical_monitors
+            .into_iter()
+            .map(|(x, y, scale, _transform, primary, monitors)| {
+                (x, y, scale, transform, primary, monitors)
+            })
+            .collect();
+
+        proxy
+            .call::<_, _, ()>(
+                "ApplyMonitorsConfig",
+                &(serial, 1u32, logical_monitors, std::collections::HashMap::<String, Value>::new()),
+            )
+            .map_err(|err| RotationError::BackendError(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn get_rotation_state(&self) -> Result<Orientation, RotationError> {
+        let connection =
+            Connection::session().map_err(|err| RotationError::BackendError(err.to_string()))?;
+        let proxy = Self::proxy(&connection)?;
+
+        let (_serial, _monitors, logical_monitors, _properties): DisplayConfigState = proxy
+            .call("GetCurrentState", &())
+            .map_err(|err| RotationError::BackendError(err.to_string()))?;
+
+        let transform = logical_monitors
+            .first()
+            .map(|(_, _, _, transform, ..)| *transform)
+            .unwrap_or(TRANSFORM_NORMAL);
+        Ok(Self::orientation_for(transform))
+    }
+}
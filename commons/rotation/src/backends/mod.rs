@@ -0,0 +1,7 @@
+mod gnome;
+mod sway;
+mod wlroots;
+
+pub use gnome::GnomeBackend;
+pub use sway::SwayBackend;
+pub use wlroots::WlrootsBackend;
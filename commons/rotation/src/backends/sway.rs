@@ -0,0 +1,58 @@
+use crate::{DisplayManager, Orientation, RotationError};
+
+/// Rotates outputs on sway via its IPC socket (`output * transform ...`).
+pub struct SwayBackend;
+
+impl SwayBackend {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn transform_for(orientation: Orientation) -> &'static str {
+        match orientation {
+            Orientation::Normal => "normal",
+            Orientation::Left => "90",
+            Orientation::Right => "270",
+            Orientation::UpsideDown => "180",
+        }
+    }
+
+    fn orientation_for(transform: &str) -> Orientation {
+        match transform {
+            "90" => Orientation::Left,
+            "180" => Orientation::UpsideDown,
+            "270" => Orientation::Right,
+            _ => Orientation::Normal,
+        }
+    }
+}
+
+impl Default for SwayBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayManager for SwayBackend {
+    fn change_rotation_state(&self, orientation: Orientation) -> Result<(), RotationError> {
+        let mut connection =
+            swayipc::Connection::new().map_err(|err| RotationError::BackendError(err.to_string()))?;
+        connection
+            .run_command(format!("output * transform {}", Self::transform_for(orientation)))
+            .map_err(|err| RotationError::BackendError(err.to_string()))?;
+        Ok(())
+    }
+
+    fn get_rotation_state(&self) -> Result<Orientation, RotationError> {
+        let mut connection =
+            swayipc::Connection::new().map_err(|err| RotationError::BackendError(err.to_string()))?;
+        let outputs = connection
+            .get_outputs()
+            .map_err(|err| RotationError::BackendError(err.to_string()))?;
+        let transform = outputs
+            .first()
+            .and_then(|output| output.transform.clone())
+            .unwrap_or_default();
+        Ok(Self::orientation_for(&transform))
+    }
+}
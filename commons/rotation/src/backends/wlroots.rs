@@ -0,0 +1,30 @@
+use crate::{DisplayManager, Orientation, RotationError};
+
+/// Generic wlroots-based compositors that only speak
+/// `wlr-output-management-unstable-v1` and have no richer rotation IPC of
+/// their own (sway is handled separately via [`crate::SwayBackend`]).
+pub struct WlrootsBackend;
+
+impl WlrootsBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for WlrootsBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayManager for WlrootsBackend {
+    fn change_rotation_state(&self, _orientation: Orientation) -> Result<(), RotationError> {
+        Err(RotationError::Unsupported(
+            "wlr-output-management transform requests are not wired up yet".to_string(),
+        ))
+    }
+
+    fn get_rotation_state(&self) -> Result<Orientation, RotationError> {
+        Ok(Orientation::Normal)
+    }
+}
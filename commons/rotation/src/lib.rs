@@ -0,0 +1,115 @@
+use std::env;
+use std::fmt;
+
+pub mod backends;
+
+pub use backends::{GnomeBackend, SwayBackend, WlrootsBackend};
+
+/// Screen orientation, independent of how any particular compositor names
+/// its output transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    #[default]
+    Normal,
+    Left,
+    Right,
+    UpsideDown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RotationError {
+    /// The backend understood the request but can't fulfil it.
+    Unsupported(String),
+    /// The backend's IPC/D-Bus call itself failed.
+    BackendError(String),
+}
+
+impl fmt::Display for RotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RotationError::Unsupported(reason) => write!(f, "rotation unsupported: {reason}"),
+            RotationError::BackendError(reason) => write!(f, "rotation backend error: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RotationError {}
+
+/// Implemented once per compositor so the rest of the shell can rotate the
+/// display without caring which one it's running under.
+pub trait DisplayManager {
+    fn change_rotation_state(&self, orientation: Orientation) -> Result<(), RotationError>;
+    fn get_rotation_state(&self) -> Result<Orientation, RotationError>;
+}
+
+/// Whether auto-rotation (driven by the accelerometer) is locked to the
+/// current orientation, or free to follow sensor input. Backed by the
+/// settings panel's rotation-lock toggle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RotationLock {
+    locked: bool,
+}
+
+impl RotationLock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Flips the lock and returns the new state.
+    pub fn toggle(&mut self) -> bool {
+        self.locked = !self.locked;
+        self.locked
+    }
+
+    /// Whether an orientation reading from the accelerometer should be
+    /// applied right now.
+    pub fn allows_auto_rotate(&self) -> bool {
+        !self.locked
+    }
+}
+
+/// Picks the backend for the running compositor from `XDG_CURRENT_DESKTOP`,
+/// falling back to the generic wlroots backend when it isn't one we
+/// recognize by name.
+pub fn detect_backend() -> Box<dyn DisplayManager> {
+    let desktop = env::var("XDG_CURRENT_DESKTOP")
+        .unwrap_or_default()
+        .to_lowercase();
+
+    if desktop.contains("gnome") {
+        tracing::debug!("detected GNOME/mutter, using GnomeBackend for rotation");
+        Box::new(GnomeBackend::new())
+    } else if desktop.contains("sway") {
+        tracing::debug!("detected sway, using SwayBackend for rotation");
+        Box::new(SwayBackend::new())
+    } else {
+        tracing::debug!("no recognized compositor in XDG_CURRENT_DESKTOP, using WlrootsBackend for rotation");
+        Box::new(WlrootsBackend::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_flips_and_returns_the_new_state() {
+        let mut lock = RotationLock::new();
+        assert!(!lock.is_locked());
+        assert!(lock.toggle());
+        assert!(lock.is_locked());
+        assert!(!lock.toggle());
+    }
+
+    #[test]
+    fn allows_auto_rotate_only_when_unlocked() {
+        let mut lock = RotationLock::new();
+        assert!(lock.allows_auto_rotate());
+        lock.toggle();
+        assert!(!lock.allows_auto_rotate());
+    }
+}
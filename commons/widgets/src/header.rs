@@ -0,0 +1,37 @@
+/// A trailing action button rendered on the right side of a [`HeaderProps`].
+#[derive(Debug, Clone)]
+pub struct HeaderAction {
+    pub icon: String,
+    pub on_click_id: String,
+}
+
+/// Shared config for the title bar every settings/app screen renders at its
+/// top: a title, an optional back button, and an optional trailing action.
+/// Pulling this into one place means screens stop hand-rolling slightly
+/// different paddings/back-button wiring every time.
+#[derive(Debug, Clone)]
+pub struct HeaderProps {
+    pub title: String,
+    pub show_back: bool,
+    pub action: Option<HeaderAction>,
+}
+
+impl HeaderProps {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            show_back: true,
+            action: None,
+        }
+    }
+
+    pub fn without_back(mut self) -> Self {
+        self.show_back = false;
+        self
+    }
+
+    pub fn with_action(mut self, action: HeaderAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
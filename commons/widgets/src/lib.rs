@@ -0,0 +1,3 @@
+pub mod header;
+
+pub use header::{HeaderAction, HeaderProps};
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use zbus::zvariant::{Optional, Type};
+
+/// A Bluetooth device as reported by [`crate::BluetoothBusInterface::connected_devices`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct BtDeviceInfo {
+    pub name: String,
+    pub mac: String,
+    /// BlueZ's `Icon` hint, e.g. `"audio-headset"`, `"input-mouse"`.
+    /// Empty if the device doesn't advertise one.
+    pub icon: String,
+    /// 0-100, if the device exposes `org.bluez.Battery1`.
+    pub battery: Optional<u8>,
+}
@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use zbus::dbus_interface;
+use zbus::zvariant::OwnedObjectPath;
+
+use crate::device_info::BtDeviceInfo;
+
+/// How often [`spawn_notification_stream`] polls for connected-device
+/// changes, if the caller doesn't have a better interval in mind.
+pub const DEFAULT_NOTIFICATION_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Exposes the Bluetooth adapter's power state and connected devices over
+/// D-Bus, so the greeter/status bar/settings app don't each need their
+/// own BlueZ client plumbing - the Bluetooth equivalent of
+/// `wireless::WirelessBusInterface`.
+pub struct BluetoothBusInterface {
+    connection: zbus::Connection,
+    adapter_path: OwnedObjectPath,
+    known_connected: Mutex<HashSet<String>>,
+}
+
+impl BluetoothBusInterface {
+    pub fn new(connection: zbus::Connection, adapter_path: OwnedObjectPath) -> Self {
+        Self {
+            connection,
+            adapter_path,
+            known_connected: Mutex::new(HashSet::new()),
+        }
+    }
+
+    async fn powered(&self) -> zbus::Result<bool> {
+        self.adapter().await?.powered().await
+    }
+
+    async fn adapter(&self) -> zbus::Result<bluez::AdapterProxy<'_>> {
+        bluez::AdapterProxy::builder(&self.connection)
+            .path(self.adapter_path.clone())?
+            .build()
+            .await
+    }
+
+    async fn fetch_connected_devices(&self) -> zbus::Result<Vec<BtDeviceInfo>> {
+        let object_manager = bluez::ObjectManagerProxy::builder(&self.connection).build().await?;
+        let paths = object_manager.device_paths_under(self.adapter_path.as_str()).await?;
+
+        let mut devices = Vec::new();
+        for path in paths {
+            let device = bluez::DeviceProxy::builder(&self.connection)
+                .path(path.clone())?
+                .build()
+                .await?;
+            if !device.connected().await? {
+                continue;
+            }
+
+            let battery = bluez::BatteryProxy::builder(&self.connection)
+                .path(path)?
+                .build()
+                .await
+                .ok();
+            let battery = match battery {
+                Some(battery) => battery.percentage().await.ok(),
+                None => None,
+            };
+
+            devices.push(BtDeviceInfo {
+                name: device.alias().await?,
+                mac: device.address().await?,
+                icon: device.icon().await.unwrap_or_default(),
+                battery: battery.into(),
+            });
+        }
+        Ok(devices)
+    }
+}
+
+/// Periodically diffs the connected-device set and emits
+/// [`BluetoothBusInterface::notification`] for each device that
+/// connected or disconnected since the last poll, on the given
+/// `interval`.
+pub async fn spawn_notification_stream(
+    interface: std::sync::Arc<BluetoothBusInterface>,
+    connection: zbus::Connection,
+    object_path: OwnedObjectPath,
+    interval: Duration,
+) -> zbus::Result<()> {
+    let signal_ctxt = zbus::SignalContext::new(&connection, object_path)?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+
+            let devices = match interface.fetch_connected_devices().await {
+                Ok(devices) => devices,
+                Err(err) => {
+                    tracing::warn!(%err, "failed to fetch connected bluetooth devices");
+                    continue;
+                }
+            };
+
+            let mut known = interface.known_connected.lock().await;
+            let now_connected: HashSet<String> = devices.iter().map(|device| device.mac.clone()).collect();
+
+            for device in &devices {
+                if known.contains(&device.mac) {
+                    continue;
+                }
+                if let Err(err) = BluetoothBusInterface::notification(
+                    &signal_ctxt,
+                    device.mac.clone(),
+                    device.name.clone(),
+                    true,
+                )
+                .await
+                {
+                    tracing::warn!(%err, "failed to emit bluetooth connected notification");
+                }
+            }
+
+            for mac in known.iter().filter(|mac| !now_connected.contains(*mac)) {
+                if let Err(err) =
+                    BluetoothBusInterface::notification(&signal_ctxt, mac.clone(), String::new(), false).await
+                {
+                    tracing::warn!(%err, "failed to emit bluetooth disconnected notification");
+                }
+            }
+
+            *known = now_connected;
+        }
+    });
+    Ok(())
+}
+
+#[dbus_interface(name = "com.mecha.Bluetooth")]
+impl BluetoothBusInterface {
+    /// Returns `(enabled, connected_to)`, where `connected_to` is the
+    /// first connected device's name, or empty if nothing is connected.
+    /// The status bar's indicator uses this to show "connected to
+    /// Headset" without enumerating devices itself.
+    async fn status(&self) -> zbus::fdo::Result<(bool, String)> {
+        let enabled = self.powered().await.map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        if !enabled {
+            return Ok((false, String::new()));
+        }
+
+        let devices = self
+            .fetch_connected_devices()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        let connected_to = devices.into_iter().next().map(|device| device.name).unwrap_or_default();
+        Ok((enabled, connected_to))
+    }
+
+    async fn enable(&self) -> zbus::fdo::Result<()> {
+        self.adapter()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?
+            .set_powered(true)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    async fn disable(&self) -> zbus::fdo::Result<()> {
+        self.adapter()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?
+            .set_powered(false)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Lists currently connected devices, with name/mac/icon and a
+    /// battery percentage when the device reports one.
+    async fn connected_devices(&self) -> zbus::fdo::Result<Vec<BtDeviceInfo>> {
+        self.fetch_connected_devices()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Emitted by [`spawn_notification_stream`] when a device connects
+    /// (`connected = true`) or disconnects (`connected = false`, `name`
+    /// left empty since BlueZ may have already dropped the object).
+    #[dbus_interface(signal)]
+    async fn notification(
+        signal_ctxt: &zbus::SignalContext<'_>,
+        mac: String,
+        name: String,
+        connected: bool,
+    ) -> zbus::Result<()>;
+}
@@ -0,0 +1,5 @@
+pub mod device_info;
+pub mod interface;
+
+pub use device_info::BtDeviceInfo;
+pub use interface::{spawn_notification_stream, BluetoothBusInterface, DEFAULT_NOTIFICATION_INTERVAL};
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use zbus::dbus_interface;
+use zbus::zvariant::OwnedObjectPath;
+
+use crate::sysfs::Brightness;
+
+/// Exposes the sysfs-backed [`Brightness`] control over D-Bus, so the
+/// status bar and settings panel share one source of truth instead of each
+/// shelling out to sysfs themselves.
+pub struct BrightnessBusInterface {
+    brightness: Brightness,
+}
+
+impl BrightnessBusInterface {
+    pub fn new(brightness: Brightness) -> Self {
+        Self { brightness }
+    }
+
+    fn step(&self, delta: i16) -> zbus::fdo::Result<u8> {
+        let current = self
+            .brightness
+            .get_brightness_percent()
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        let next = (current as i16 + delta).clamp(0, 100) as u8;
+        self.brightness
+            .set_brightness_percent(next)
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        Ok(next)
+    }
+}
+
+/// How often [`spawn_notification_stream`] emits `notification` by default,
+/// if the caller doesn't have a better interval in mind.
+pub const DEFAULT_NOTIFICATION_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Periodically emits [`BrightnessBusInterface::notification`] with the
+/// current brightness percentage, on the given `interval`. Mirrors
+/// `wireless::spawn_notification_stream`: the signal context is built once
+/// up front, and the first emission happens immediately via
+/// `tokio::time::interval`'s immediate-first-tick behavior.
+pub async fn spawn_notification_stream(
+    interface: Arc<BrightnessBusInterface>,
+    connection: zbus::Connection,
+    object_path: OwnedObjectPath,
+    interval: Duration,
+) -> zbus::Result<()> {
+    let signal_ctxt = zbus::SignalContext::new(&connection, object_path)?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match interface.brightness.get_brightness_percent() {
+                Ok(percent) => {
+                    if let Err(err) = BrightnessBusInterface::notification(&signal_ctxt, percent).await {
+                        tracing::warn!(%err, "failed to emit brightness notification signal");
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(%err, "failed to read brightness for notification stream");
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+#[dbus_interface(name = "com.mecha.Brightness")]
+impl BrightnessBusInterface {
+    /// Current brightness as a 0-100 percentage.
+    async fn get(&self) -> zbus::fdo::Result<u8> {
+        self.brightness
+            .get_brightness_percent()
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Set brightness to an absolute percentage.
+    async fn set(&self, percent: u8) -> zbus::fdo::Result<()> {
+        self.brightness
+            .set_brightness_percent(percent)
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Raise brightness by `step` percentage points, clamped to 100.
+    /// Returns the resulting percentage.
+    async fn up(&self, step: u8) -> zbus::fdo::Result<u8> {
+        self.step(step as i16)
+    }
+
+    /// Lower brightness by `step` percentage points, clamped to 0.
+    /// Returns the resulting percentage.
+    async fn down(&self, step: u8) -> zbus::fdo::Result<u8> {
+        self.step(-(step as i16))
+    }
+
+    /// Emitted by [`spawn_notification_stream`] whenever it polls the
+    /// current brightness.
+    #[dbus_interface(signal)]
+    async fn notification(signal_ctxt: &zbus::SignalContext<'_>, percent: u8) -> zbus::Result<()>;
+}
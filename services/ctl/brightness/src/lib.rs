@@ -0,0 +1,5 @@
+pub mod interface;
+pub mod sysfs;
+
+pub use interface::{spawn_notification_stream, BrightnessBusInterface, DEFAULT_NOTIFICATION_INTERVAL};
+pub use sysfs::{Brightness, BrightnessError};
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const BACKLIGHT_DIR: &str = "/sys/class/backlight";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrightnessError {
+    NoBacklightDevice,
+    Io(String),
+}
+
+impl fmt::Display for BrightnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrightnessError::NoBacklightDevice => write!(f, "no backlight device found under {BACKLIGHT_DIR}"),
+            BrightnessError::Io(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for BrightnessError {}
+
+/// Reads/writes backlight brightness through `/sys/class/backlight/*`.
+pub struct Brightness {
+    device_dir: PathBuf,
+}
+
+impl Brightness {
+    /// Picks `preferred` by device name if given and present, otherwise
+    /// the first backlight device sysfs reports.
+    pub fn new(preferred: Option<&str>) -> Result<Self, BrightnessError> {
+        Ok(Self {
+            device_dir: find_device(preferred)?,
+        })
+    }
+
+    fn max_brightness(&self) -> Result<u32, BrightnessError> {
+        read_u32(&self.device_dir.join("max_brightness"))
+    }
+
+    fn raw_brightness(&self) -> Result<u32, BrightnessError> {
+        read_u32(&self.device_dir.join("brightness"))
+    }
+
+    pub fn get_brightness_percent(&self) -> Result<u8, BrightnessError> {
+        let max = self.max_brightness()?;
+        let current = self.raw_brightness()?;
+        Ok(raw_to_percent(current, max))
+    }
+
+    /// Clamps the written raw value to `[1, max]`, so this can never turn
+    /// the screen fully off.
+    pub fn set_brightness_percent(&self, pct: u8) -> Result<(), BrightnessError> {
+        let max = self.max_brightness()?;
+        let raw = percent_to_raw(pct, max);
+        fs::write(self.device_dir.join("brightness"), raw.to_string())
+            .map_err(|err| BrightnessError::Io(err.to_string()))
+    }
+}
+
+fn find_device(preferred: Option<&str>) -> Result<PathBuf, BrightnessError> {
+    let entries = fs::read_dir(BACKLIGHT_DIR).map_err(|err| BrightnessError::Io(err.to_string()))?;
+    let mut devices: Vec<PathBuf> = entries.filter_map(Result::ok).map(|entry| entry.path()).collect();
+    devices.sort();
+
+    if let Some(name) = preferred {
+        if let Some(path) = devices
+            .iter()
+            .find(|path| path.file_name().and_then(|n| n.to_str()) == Some(name))
+        {
+            return Ok(path.clone());
+        }
+    }
+
+    devices.into_iter().next().ok_or(BrightnessError::NoBacklightDevice)
+}
+
+fn read_u32(path: &Path) -> Result<u32, BrightnessError> {
+    fs::read_to_string(path)
+        .map_err(|err| BrightnessError::Io(err.to_string()))?
+        .trim()
+        .parse()
+        .map_err(|_| BrightnessError::Io(format!("invalid integer in {}", path.display())))
+}
+
+fn raw_to_percent(raw: u32, max: u32) -> u8 {
+    if max == 0 {
+        return 0;
+    }
+    ((raw as f64 / max as f64) * 100.0).round().clamp(0.0, 100.0) as u8
+}
+
+fn percent_to_raw(pct: u8, max: u32) -> u32 {
+    let pct = pct.min(100);
+    let raw = ((pct as f64 / 100.0) * max as f64).round() as u32;
+    raw.clamp(1, max.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_to_percent_rounds_to_nearest() {
+        assert_eq!(raw_to_percent(128, 255), 50);
+    }
+
+    #[test]
+    fn percent_to_raw_never_goes_below_one() {
+        assert_eq!(percent_to_raw(0, 255), 1);
+    }
+
+    #[test]
+    fn percent_to_raw_clamps_above_hundred() {
+        assert_eq!(percent_to_raw(150, 255), 255);
+    }
+}
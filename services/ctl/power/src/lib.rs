@@ -0,0 +1,69 @@
+use std::fmt;
+
+pub mod proxy;
+
+pub use proxy::PowerProfilesProxy;
+
+/// A system power profile, mirroring `org.freedesktop.UPower.PowerProfiles`'s
+/// `ActiveProfile` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerProfile {
+    PowerSaver,
+    Balanced,
+    Performance,
+}
+
+impl PowerProfile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PowerProfile::PowerSaver => "power-saver",
+            PowerProfile::Balanced => "balanced",
+            PowerProfile::Performance => "performance",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, UnknownProfile> {
+        match value {
+            "power-saver" => Ok(PowerProfile::PowerSaver),
+            "balanced" => Ok(PowerProfile::Balanced),
+            "performance" => Ok(PowerProfile::Performance),
+            other => Err(UnknownProfile(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for PowerProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A power-profiles-daemon `ActiveProfile` value this crate doesn't
+/// recognize, e.g. a distro-specific extra profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownProfile(pub String);
+
+impl fmt::Display for UnknownProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown power profile: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownProfile {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_as_str_and_parse() {
+        for profile in [PowerProfile::PowerSaver, PowerProfile::Balanced, PowerProfile::Performance] {
+            assert_eq!(PowerProfile::parse(profile.as_str()), Ok(profile));
+        }
+    }
+
+    #[test]
+    fn unrecognized_profile_is_reported_rather_than_defaulted() {
+        assert_eq!(PowerProfile::parse("turbo"), Err(UnknownProfile("turbo".to_string())));
+    }
+}
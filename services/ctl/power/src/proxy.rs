@@ -0,0 +1,17 @@
+use zbus::dbus_proxy;
+
+/// Thin proxy over `org.freedesktop.UPower.PowerProfiles`, the standard
+/// power-profiles-daemon interface most desktop/mobile distros ship.
+#[dbus_proxy(
+    interface = "org.freedesktop.UPower.PowerProfiles",
+    default_service = "org.freedesktop.UPower.PowerProfiles",
+    default_path = "/org/freedesktop/UPower/PowerProfiles"
+)]
+trait PowerProfiles {
+    /// One of `"power-saver"`, `"balanced"`, `"performance"`.
+    #[dbus_proxy(property)]
+    fn active_profile(&self) -> zbus::Result<String>;
+
+    #[dbus_proxy(property)]
+    fn set_active_profile(&self, value: String) -> zbus::Result<()>;
+}
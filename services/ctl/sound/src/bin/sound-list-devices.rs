@@ -0,0 +1,43 @@
+//! Small CLI used to sanity-check the sound service against whatever
+//! PulseAudio server is running: `cargo run --bin sound-list-devices`.
+
+use sound::Sound;
+
+struct SourceInformation {
+    name: String,
+    description: String,
+}
+
+fn main() {
+    let sound = match Sound::new() {
+        Ok(sound) => sound,
+        Err(err) => {
+            eprintln!("failed to connect to pulseaudio: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("Sinks:");
+    for sink in sound.list_sinks().unwrap_or_default() {
+        println!(
+            "  {} ({}){}",
+            sink.name,
+            sink.description,
+            if sink.is_default { " [default]" } else { "" }
+        );
+    }
+
+    println!("Sources:");
+    for source in sound.list_sources().unwrap_or_default() {
+        let info = SourceInformation {
+            name: source.name.clone(),
+            description: source.description.clone(),
+        };
+        println!(
+            "  {} ({}){}",
+            info.name,
+            info.description,
+            if source.is_default { " [default]" } else { "" }
+        );
+    }
+}
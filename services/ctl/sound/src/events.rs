@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc;
+
+use libpulse_binding::context::subscribe::{Facility, InterestMaskSet, Operation};
+
+use crate::Sound;
+
+/// A change PulseAudio reported through its subscription API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeEvent {
+    SinkChanged { index: u32 },
+    SourceChanged { index: u32 },
+    SinkInputChanged { index: u32 },
+}
+
+impl Sound {
+    /// Subscribe to sink/source/sink-input changes and get a channel that
+    /// receives an event every time PulseAudio reports one. Call this once
+    /// and read from the channel instead of polling `list_sinks`/
+    /// `list_sources` on a timer.
+    pub fn subscribe_volume_events(&self) -> mpsc::Receiver<VolumeEvent> {
+        let (tx, rx) = mpsc::channel();
+
+        self.context
+            .borrow_mut()
+            .set_subscribe_callback(Some(Box::new(move |facility, operation, index| {
+                if !matches!(operation, Some(Operation::Changed)) {
+                    return;
+                }
+                let event = match facility {
+                    Some(Facility::Sink) => VolumeEvent::SinkChanged { index },
+                    Some(Facility::Source) => VolumeEvent::SourceChanged { index },
+                    Some(Facility::SinkInput) => VolumeEvent::SinkInputChanged { index },
+                    _ => return,
+                };
+                let _ = tx.send(event);
+            })));
+
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        self.context.borrow_mut().subscribe(
+            InterestMaskSet::SINK | InterestMaskSet::SOURCE | InterestMaskSet::SINK_INPUT,
+            move |_success| {
+                *done_cb.borrow_mut() = true;
+            },
+        );
+        self.wait_until(move || *done.borrow());
+
+        rx
+    }
+}
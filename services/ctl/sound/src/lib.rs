@@ -0,0 +1,268 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::introspect::SinkInfo as PaSinkInfo;
+use libpulse_binding::context::{Context, FlagSet as ContextFlagSet};
+use libpulse_binding::mainloop::standard::{IterateResult, Mainloop};
+use libpulse_binding::proplist::Proplist;
+
+pub mod events;
+pub mod sink_input;
+pub mod source;
+pub mod volume_step;
+
+pub use events::VolumeEvent;
+pub use sink_input::SinkInputInfo;
+pub use source::SourceInfo;
+pub use volume_step::DEFAULT_VOLUME_STEP;
+
+/// A PulseAudio output sink, as presented to UIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinkInfo {
+    /// The PulseAudio sink name, e.g. `alsa_output.pci-0000_00_1f.3.analog-stereo`.
+    pub name: String,
+    /// The human-readable description, e.g. `Built-in Audio`.
+    pub description: String,
+    pub is_default: bool,
+}
+
+#[derive(Debug)]
+pub enum SoundError {
+    ConnectionFailed(String),
+    OperationFailed(String),
+}
+
+impl std::fmt::Display for SoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SoundError::ConnectionFailed(msg) => write!(f, "pulseaudio connection failed: {msg}"),
+            SoundError::OperationFailed(msg) => write!(f, "pulseaudio operation failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SoundError {}
+
+/// Thin synchronous wrapper around a PulseAudio context, used by the sound
+/// settings screen and the status bar's volume indicator.
+pub struct Sound {
+    pub(crate) mainloop: Rc<RefCell<Mainloop>>,
+    pub(crate) context: Rc<RefCell<Context>>,
+}
+
+impl Sound {
+    pub fn new() -> Result<Self, SoundError> {
+        let mut proplist = Proplist::new().ok_or_else(|| {
+            SoundError::ConnectionFailed("could not create proplist".to_string())
+        })?;
+        proplist
+            .set_str(
+                libpulse_binding::proplist::properties::APPLICATION_NAME,
+                "mechanix-sound",
+            )
+            .map_err(|_| SoundError::ConnectionFailed("could not set app name".to_string()))?;
+
+        let mainloop = Rc::new(RefCell::new(
+            Mainloop::new().ok_or_else(|| {
+                SoundError::ConnectionFailed("could not create mainloop".to_string())
+            })?,
+        ));
+
+        let context = Rc::new(RefCell::new(
+            Context::new_with_proplist(&*mainloop.borrow(), "mechanix-sound", &proplist)
+                .ok_or_else(|| {
+                    SoundError::ConnectionFailed("could not create context".to_string())
+                })?,
+        ));
+
+        context
+            .borrow_mut()
+            .connect(None, ContextFlagSet::NOFLAGS, None)
+            .map_err(|e| SoundError::ConnectionFailed(e.to_string().unwrap_or_default()))?;
+
+        loop {
+            match mainloop.borrow_mut().iterate(true) {
+                IterateResult::Success(_) => {}
+                IterateResult::Err(e) => {
+                    return Err(SoundError::ConnectionFailed(e.to_string().unwrap_or_default()))
+                }
+                IterateResult::Quit(_) => {
+                    return Err(SoundError::ConnectionFailed("mainloop quit".to_string()))
+                }
+            }
+            if context.borrow().get_state() == libpulse_binding::context::State::Ready {
+                break;
+            }
+        }
+
+        Ok(Self { mainloop, context })
+    }
+
+    /// List every output sink known to PulseAudio, annotated with whether it
+    /// is currently the system default.
+    pub fn list_sinks(&self) -> Result<Vec<SinkInfo>, SoundError> {
+        let sinks: Rc<RefCell<Vec<SinkInfo>>> = Rc::new(RefCell::new(Vec::new()));
+        let default_sink_name: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
+
+        {
+            let default_sink_name = default_sink_name.clone();
+            let done = Rc::new(RefCell::new(false));
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_server_info(move |info| {
+                    *default_sink_name.borrow_mut() =
+                        info.default_sink_name.as_ref().map(|s| s.to_string());
+                    *done_cb.borrow_mut() = true;
+                });
+            self.wait_until(move || *done.borrow());
+        }
+
+        let done = Rc::new(RefCell::new(false));
+        {
+            let sinks = sinks.clone();
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_sink_info_list(move |result| {
+                    if let ListResult::Item(info) = result {
+                        sinks.borrow_mut().push(pa_sink_to_sink_info(info));
+                    } else {
+                        *done_cb.borrow_mut() = true;
+                    }
+                });
+        }
+        self.wait_until(move || *done.borrow());
+
+        let default_name = default_sink_name.borrow().clone();
+        let mut sinks = sinks.borrow().clone();
+        for sink in sinks.iter_mut() {
+            sink.is_default = default_name.as_deref() == Some(sink.name.as_str());
+        }
+        Ok(sinks)
+    }
+
+    /// Switch the system default output sink by its PulseAudio name.
+    pub fn set_default_sink(&self, name: &str) -> Result<(), SoundError> {
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        let owned_name = name.to_string();
+        self.context
+            .borrow_mut()
+            .set_default_sink(name, move |success| {
+                *done_cb.borrow_mut() = true;
+                if !success {
+                    tracing::warn!(sink = owned_name, "pulseaudio refused to set default sink");
+                }
+            });
+        self.wait_until(move || *done.borrow());
+        Ok(())
+    }
+
+    /// Set `sink_name` to `volume_percent` (0-100), as a two-channel
+    /// (stereo) volume.
+    pub fn set_output_volumes(&self, sink_name: &str, volume_percent: u8) -> Result<(), SoundError> {
+        use libpulse_binding::volume::{ChannelVolumes, Volume};
+
+        let volume = Volume((volume_percent as f64 / 100.0 * Volume::NORMAL.0 as f64) as u32);
+        let mut channel_volumes = ChannelVolumes::default();
+        channel_volumes.set(2, volume);
+
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        self.context.borrow_mut().introspect().set_sink_volume_by_name(
+            sink_name,
+            &channel_volumes,
+            Some(Box::new(move |_success| {
+                *done_cb.borrow_mut() = true;
+            })),
+        );
+        self.wait_until(move || *done.borrow());
+        Ok(())
+    }
+
+    /// Current average volume of `sink_name`, as a 0-100 percentage.
+    pub fn get_output_volume(&self, sink_name: &str) -> Result<u8, SoundError> {
+        use libpulse_binding::volume::Volume;
+
+        let volume: Rc<RefCell<u8>> = Rc::new(RefCell::new(0));
+        let done = Rc::new(RefCell::new(false));
+        {
+            let volume = volume.clone();
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_sink_info_by_name(sink_name, move |result| {
+                    if let ListResult::Item(info) = result {
+                        *volume.borrow_mut() =
+                            (info.volume.avg().0 as f64 / Volume::NORMAL.0 as f64 * 100.0) as u8;
+                    }
+                    *done_cb.borrow_mut() = true;
+                });
+        }
+        self.wait_until(move || *done.borrow());
+        let volume = *volume.borrow();
+        Ok(volume)
+    }
+
+    /// Flip `sink_name`'s mute state and return the new state.
+    pub fn toggle_mute(&self, sink_name: &str) -> Result<bool, SoundError> {
+        let currently_muted: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+        let done = Rc::new(RefCell::new(false));
+        {
+            let currently_muted = currently_muted.clone();
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_sink_info_by_name(sink_name, move |result| {
+                    if let ListResult::Item(info) = result {
+                        *currently_muted.borrow_mut() = info.mute;
+                    }
+                    *done_cb.borrow_mut() = true;
+                });
+        }
+        self.wait_until(move || *done.borrow());
+
+        let new_mute = !*currently_muted.borrow();
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        self.context
+            .borrow_mut()
+            .introspect()
+            .set_sink_mute_by_name(
+                sink_name,
+                new_mute,
+                Some(Box::new(move |_success| {
+                    *done_cb.borrow_mut() = true;
+                })),
+            );
+        self.wait_until(move || *done.borrow());
+        Ok(new_mute)
+    }
+
+    pub(crate) fn wait_until(&self, condition: impl Fn() -> bool) {
+        while !condition() {
+            match self.mainloop.borrow_mut().iterate(true) {
+                IterateResult::Success(_) => {}
+                IterateResult::Err(_) | IterateResult::Quit(_) => break,
+            }
+        }
+    }
+}
+
+fn pa_sink_to_sink_info(info: &PaSinkInfo) -> SinkInfo {
+    SinkInfo {
+        name: info.name.as_ref().map(|s| s.to_string()).unwrap_or_default(),
+        description: info
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_default(),
+        is_default: false,
+    }
+}
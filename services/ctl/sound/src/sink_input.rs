@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::introspect::SinkInputInfo as PaSinkInputInfo;
+use libpulse_binding::volume::{ChannelVolumes, Volume};
+
+use crate::{Sound, SoundError};
+
+/// A single application's audio stream, as it appears in PulseAudio's
+/// sink-input list. This is what backs per-app volume sliders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinkInputInfo {
+    pub index: u32,
+    pub app_name: String,
+    /// 0-100, linear scale.
+    pub volume_percent: u8,
+}
+
+impl Sound {
+    /// List every application currently playing audio.
+    pub fn list_sink_inputs(&self) -> Result<Vec<SinkInputInfo>, SoundError> {
+        let inputs: Rc<RefCell<Vec<SinkInputInfo>>> = Rc::new(RefCell::new(Vec::new()));
+        let done = Rc::new(RefCell::new(false));
+        {
+            let inputs = inputs.clone();
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_sink_input_info_list(move |result| {
+                    if let ListResult::Item(info) = result {
+                        inputs.borrow_mut().push(pa_sink_input_to_info(info));
+                    } else {
+                        *done_cb.borrow_mut() = true;
+                    }
+                });
+        }
+        self.wait_until(move || *done.borrow());
+        let inputs = inputs.borrow().clone();
+        Ok(inputs)
+    }
+
+    /// Set a single application stream's volume (0-100).
+    pub fn set_sink_input_volume(&self, index: u32, volume_percent: u8) -> Result<(), SoundError> {
+        let volume = Volume((volume_percent as f64 / 100.0 * Volume::NORMAL.0 as f64) as u32);
+        let mut channel_volumes = ChannelVolumes::default();
+        channel_volumes.set(2, volume);
+
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        self.context.borrow_mut().introspect().set_sink_input_volume(
+            index,
+            &channel_volumes,
+            Some(Box::new(move |_success| {
+                *done_cb.borrow_mut() = true;
+            })),
+        );
+        self.wait_until(move || *done.borrow());
+        Ok(())
+    }
+}
+
+fn pa_sink_input_to_info(info: &PaSinkInputInfo) -> SinkInputInfo {
+    let volume_percent = (info.volume.avg().0 as f64 / Volume::NORMAL.0 as f64 * 100.0) as u8;
+    SinkInputInfo {
+        index: info.index,
+        app_name: info
+            .proplist
+            .get_str("application.name")
+            .unwrap_or_else(|| info.name.as_ref().map(|s| s.to_string()).unwrap_or_default()),
+        volume_percent,
+    }
+}
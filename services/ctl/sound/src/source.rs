@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::introspect::SourceInfo as PaSourceInfo;
+use libpulse_binding::volume::{ChannelVolumes, Volume};
+
+use crate::{Sound, SoundError};
+
+/// A PulseAudio input source (microphone), as presented to UIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceInfo {
+    pub name: String,
+    pub description: String,
+    pub is_default: bool,
+}
+
+impl Sound {
+    pub fn list_sources(&self) -> Result<Vec<SourceInfo>, SoundError> {
+        let sources: Rc<RefCell<Vec<SourceInfo>>> = Rc::new(RefCell::new(Vec::new()));
+        let default_source_name: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
+
+        {
+            let default_source_name = default_source_name.clone();
+            let done = Rc::new(RefCell::new(false));
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_server_info(move |info| {
+                    *default_source_name.borrow_mut() =
+                        info.default_source_name.as_ref().map(|s| s.to_string());
+                    *done_cb.borrow_mut() = true;
+                });
+            self.wait_until(move || *done.borrow());
+        }
+
+        let done = Rc::new(RefCell::new(false));
+        {
+            let sources = sources.clone();
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_source_info_list(move |result| {
+                    if let ListResult::Item(info) = result {
+                        sources.borrow_mut().push(pa_source_to_source_info(info));
+                    } else {
+                        *done_cb.borrow_mut() = true;
+                    }
+                });
+        }
+        self.wait_until(move || *done.borrow());
+
+        let default_name = default_source_name.borrow().clone();
+        let mut sources = sources.borrow().clone();
+        for source in sources.iter_mut() {
+            source.is_default = default_name.as_deref() == Some(source.name.as_str());
+        }
+        Ok(sources)
+    }
+
+    /// Set `source_name` to `volume_percent` (0-100), as a two-channel
+    /// (stereo) volume.
+    pub fn set_input_volume(&self, source_name: &str, volume_percent: u8) -> Result<(), SoundError> {
+        let volume = Volume((volume_percent as f64 / 100.0 * Volume::NORMAL.0 as f64) as u32);
+        let mut channel_volumes = ChannelVolumes::default();
+        channel_volumes.set(2, volume);
+
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        self.context
+            .borrow_mut()
+            .introspect()
+            .set_source_volume_by_name(
+                source_name,
+                &channel_volumes,
+                Some(Box::new(move |_success| {
+                    *done_cb.borrow_mut() = true;
+                })),
+            );
+        self.wait_until(move || *done.borrow());
+        Ok(())
+    }
+
+    /// Switch the system default input source by its PulseAudio name.
+    pub fn set_default_source(&self, name: &str) -> Result<(), SoundError> {
+        let done = Rc::new(RefCell::new(false));
+        let done_cb = done.clone();
+        let owned_name = name.to_string();
+        self.context
+            .borrow_mut()
+            .set_default_source(name, move |success| {
+                *done_cb.borrow_mut() = true;
+                if !success {
+                    tracing::warn!(source = owned_name, "pulseaudio refused to set default source");
+                }
+            });
+        self.wait_until(move || *done.borrow());
+        Ok(())
+    }
+
+    pub fn get_input_volume(&self, source_name: &str) -> Result<u8, SoundError> {
+        let volume: Rc<RefCell<u8>> = Rc::new(RefCell::new(0));
+        let done = Rc::new(RefCell::new(false));
+        {
+            let volume = volume.clone();
+            let done_cb = done.clone();
+            self.context
+                .borrow_mut()
+                .introspect()
+                .get_source_info_by_name(source_name, move |result| {
+                    if let ListResult::Item(info) = result {
+                        *volume.borrow_mut() =
+                            (info.volume.avg().0 as f64 / Volume::NORMAL.0 as f64 * 100.0) as u8;
+                    }
+                    *done_cb.borrow_mut() = true;
+                });
+        }
+        self.wait_until(move || *done.borrow());
+        let volume = *volume.borrow();
+        Ok(volume)
+    }
+}
+
+fn pa_source_to_source_info(info: &PaSourceInfo) -> SourceInfo {
+    SourceInfo {
+        name: info.name.as_ref().map(|s| s.to_string()).unwrap_or_default(),
+        description: info
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_default(),
+        is_default: false,
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Sound, SoundError};
+
+/// Default step size for `volume_up`/`volume_down`, in percentage points.
+pub const DEFAULT_VOLUME_STEP: u8 = 5;
+
+impl Sound {
+    /// Raise `sink_name`'s volume by `step` percentage points, clamped to 100.
+    pub fn volume_up(&self, sink_name: &str, step: u8) -> Result<u8, SoundError> {
+        let current = self.get_output_volume(sink_name)?;
+        let new_volume = current.saturating_add(step).min(100);
+        self.set_output_volumes(sink_name, new_volume)?;
+        Ok(new_volume)
+    }
+
+    /// Lower `sink_name`'s volume by `step` percentage points, clamped to 0.
+    pub fn volume_down(&self, sink_name: &str, step: u8) -> Result<u8, SoundError> {
+        let current = self.get_output_volume(sink_name)?;
+        let new_volume = current.saturating_sub(step);
+        self.set_output_volumes(sink_name, new_volume)?;
+        Ok(new_volume)
+    }
+}
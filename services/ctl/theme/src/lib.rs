@@ -0,0 +1,203 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use zbus::dbus_interface;
+
+pub mod proxy;
+
+pub use proxy::ThemeProxy;
+
+/// User-facing theme preference, set in the settings app and persisted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeMode {
+    Dark,
+    Light,
+    /// Follows [`ThemeDaemon::set_is_daytime`] (a schedule or ambient-light
+    /// setting) rather than a fixed choice.
+    #[default]
+    Auto,
+}
+
+impl ThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "dark",
+            ThemeMode::Light => "light",
+            ThemeMode::Auto => "auto",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, UnknownThemeMode> {
+        match value {
+            "dark" => Ok(ThemeMode::Dark),
+            "light" => Ok(ThemeMode::Light),
+            "auto" => Ok(ThemeMode::Auto),
+            other => Err(UnknownThemeMode(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ThemeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A `ThemeMode` string this crate doesn't recognize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownThemeMode(pub String);
+
+impl fmt::Display for UnknownThemeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown theme mode: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownThemeMode {}
+
+/// The two stylesheets a shell can actually apply - what [`ThemeMode::Auto`]
+/// resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolvedTheme {
+    Dark,
+    Light,
+}
+
+impl ResolvedTheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResolvedTheme::Dark => "dark",
+            ResolvedTheme::Light => "light",
+        }
+    }
+}
+
+/// Resolves `mode` to a concrete theme, following `is_daytime` (sourced
+/// from a schedule or ambient-light sensor) when `mode` is
+/// [`ThemeMode::Auto`].
+pub fn resolve_mode(mode: ThemeMode, is_daytime: bool) -> ResolvedTheme {
+    match mode {
+        ThemeMode::Dark => ResolvedTheme::Dark,
+        ThemeMode::Light => ResolvedTheme::Light,
+        ThemeMode::Auto if is_daytime => ResolvedTheme::Light,
+        ThemeMode::Auto => ResolvedTheme::Dark,
+    }
+}
+
+/// Implements a small `com.mecha.Theme` D-Bus service: the settings app
+/// calls `SetMode`, and every shell process (homescreen, status-bar,
+/// launcher) subscribes to `ModeChanged` to know when to reload its
+/// stylesheet. Only emits the signal when the *resolved* theme actually
+/// changes, so e.g. toggling between two modes that both resolve to
+/// `ResolvedTheme::Dark` doesn't trigger a needless reload.
+pub struct ThemeDaemon {
+    mode: Mutex<ThemeMode>,
+    is_daytime: AtomicBool,
+}
+
+impl ThemeDaemon {
+    pub fn new(mode: ThemeMode, is_daytime: bool) -> Self {
+        Self {
+            mode: Mutex::new(mode),
+            is_daytime: AtomicBool::new(is_daytime),
+        }
+    }
+
+    pub fn mode(&self) -> ThemeMode {
+        *self.mode.lock().unwrap()
+    }
+
+    pub fn resolved(&self) -> ResolvedTheme {
+        resolve_mode(self.mode(), self.is_daytime.load(Ordering::SeqCst))
+    }
+}
+
+#[dbus_interface(name = "com.mecha.Theme")]
+impl ThemeDaemon {
+    /// One of `"dark"`, `"light"`, `"auto"`.
+    async fn get_mode(&self) -> String {
+        self.mode().as_str().to_string()
+    }
+
+    /// Sets the preference and, if the resolved theme actually changed,
+    /// emits `ModeChanged` with the new resolved theme so subscribers
+    /// reload the matching stylesheet. An unrecognized `mode` is ignored.
+    async fn set_mode(
+        &self,
+        #[zbus(signal_context)] signal_ctxt: zbus::SignalContext<'_>,
+        mode: String,
+    ) -> zbus::fdo::Result<()> {
+        let Ok(mode) = ThemeMode::parse(&mode) else {
+            return Ok(());
+        };
+        let before = self.resolved();
+        *self.mode.lock().unwrap() = mode;
+        let after = self.resolved();
+        if before != after {
+            Self::mode_changed(&signal_ctxt, after.as_str().to_string())
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Called by the schedule/ambient-light source to report whether it's
+    /// currently day or night, re-resolving `ThemeMode::Auto` and emitting
+    /// `ModeChanged` the same way `SetMode` does.
+    async fn set_is_daytime(
+        &self,
+        #[zbus(signal_context)] signal_ctxt: zbus::SignalContext<'_>,
+        is_daytime: bool,
+    ) -> zbus::fdo::Result<()> {
+        let before = self.resolved();
+        self.is_daytime.store(is_daytime, Ordering::SeqCst);
+        let after = self.resolved();
+        if before != after {
+            Self::mode_changed(&signal_ctxt, after.as_str().to_string())
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    #[dbus_interface(signal)]
+    async fn mode_changed(signal_ctxt: &zbus::SignalContext<'_>, resolved: String) -> zbus::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_as_str_and_parse() {
+        for mode in [ThemeMode::Dark, ThemeMode::Light, ThemeMode::Auto] {
+            assert_eq!(ThemeMode::parse(mode.as_str()), Ok(mode));
+        }
+    }
+
+    #[test]
+    fn unrecognized_mode_is_reported_rather_than_defaulted() {
+        assert_eq!(ThemeMode::parse("psychedelic"), Err(UnknownThemeMode("psychedelic".to_string())));
+    }
+
+    #[test]
+    fn auto_follows_is_daytime() {
+        assert_eq!(resolve_mode(ThemeMode::Auto, true), ResolvedTheme::Light);
+        assert_eq!(resolve_mode(ThemeMode::Auto, false), ResolvedTheme::Dark);
+    }
+
+    #[test]
+    fn fixed_modes_ignore_is_daytime() {
+        assert_eq!(resolve_mode(ThemeMode::Dark, true), ResolvedTheme::Dark);
+        assert_eq!(resolve_mode(ThemeMode::Light, false), ResolvedTheme::Light);
+    }
+
+    #[test]
+    fn daemon_resolves_from_its_current_state() {
+        let daemon = ThemeDaemon::new(ThemeMode::Auto, false);
+        assert_eq!(daemon.resolved(), ResolvedTheme::Dark);
+    }
+}
@@ -0,0 +1,22 @@
+use zbus::dbus_proxy;
+
+/// Thin proxy over `com.mecha.Theme`, the self-hosted service
+/// [`crate::ThemeDaemon`] implements.
+#[dbus_proxy(
+    interface = "com.mecha.Theme",
+    default_service = "com.mecha.Theme",
+    default_path = "/com/mecha/Theme"
+)]
+trait Theme {
+    #[dbus_proxy(name = "GetMode")]
+    fn get_mode(&self) -> zbus::Result<String>;
+
+    #[dbus_proxy(name = "SetMode")]
+    fn set_mode(&self, mode: String) -> zbus::Result<()>;
+
+    #[dbus_proxy(name = "SetIsDaytime")]
+    fn set_is_daytime(&self, is_daytime: bool) -> zbus::Result<()>;
+
+    #[dbus_proxy(signal, name = "ModeChanged")]
+    fn mode_changed(&self, resolved: String) -> zbus::Result<()>;
+}
@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// Errors from talking to wpa_supplicant over D-Bus, distinguishing the
+/// two failure modes a caller actually needs to tell apart: "we never
+/// associated" vs "we associated but the passphrase was wrong" (which
+/// wpa_supplicant reports as a 4-way handshake timeout rather than a
+/// distinct error code, so we infer it).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WirelessError {
+    AssociationFailed(String),
+    PassphraseRejected,
+}
+
+impl fmt::Display for WirelessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WirelessError::AssociationFailed(reason) => write!(f, "association failed: {reason}"),
+            WirelessError::PassphraseRejected => write!(f, "passphrase rejected"),
+        }
+    }
+}
+
+impl std::error::Error for WirelessError {}
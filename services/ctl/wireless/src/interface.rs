@@ -0,0 +1,375 @@
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+use zbus::dbus_interface;
+use zbus::zvariant::OwnedObjectPath;
+
+use crate::signal::WirelessSignalLevel;
+use crate::supplicant::{network_id_from_path, Supplicant};
+use crate::WirelessError;
+
+/// How long [`WirelessBusInterface::scan`] results stay valid before a
+/// non-forced call triggers a fresh scan.
+const SCAN_CACHE_TTL: Duration = Duration::from_secs(10);
+
+type ScanResult = (String, String, WirelessSignalLevel, bool);
+
+struct ScanCache {
+    fetched_at: Instant,
+    results: Vec<ScanResult>,
+}
+
+/// Converts NetworkManager's 0-100 quality percentage to an approximate
+/// dBm reading, the same rough formula most Wi-Fi tooling uses when a
+/// driver doesn't expose real dBm itself.
+fn strength_to_dbm(strength: u8) -> i32 {
+    (strength as i32) / 2 - 100
+}
+
+/// Exposes a simplified view of the active Wi-Fi connection over D-Bus, so
+/// the greeter/status bar/settings app don't each need their own
+/// NetworkManager client plumbing.
+pub struct WirelessBusInterface {
+    connection: zbus::Connection,
+    device_path: OwnedObjectPath,
+    supplicant: Supplicant,
+    scan_cache: Mutex<Option<ScanCache>>,
+}
+
+impl WirelessBusInterface {
+    /// `device_path` is the NetworkManager wireless device used for
+    /// `info()`; `interface_path` is the matching
+    /// `fi.w1.wpa_supplicant1.Interface` object used for everything that
+    /// needs to manipulate network blocks directly (connecting, priority,
+    /// hidden SSIDs) rather than just reading state.
+    pub fn new(
+        connection: zbus::Connection,
+        device_path: OwnedObjectPath,
+        interface_path: OwnedObjectPath,
+    ) -> Self {
+        let supplicant = Supplicant::new(connection.clone(), interface_path);
+        Self {
+            connection,
+            device_path,
+            supplicant,
+            scan_cache: Mutex::new(None),
+        }
+    }
+
+    async fn connect_internal(
+        &self,
+        ssid: &str,
+        password: Option<&str>,
+        hidden: bool,
+    ) -> Result<u32, WirelessError> {
+        let network = self
+            .supplicant
+            .add_network(ssid, password, hidden)
+            .await
+            .map_err(|err| WirelessError::AssociationFailed(err.to_string()))?;
+
+        self.supplicant.select_network(&network).await.map_err(|err| {
+            // wpa_supplicant surfaces a bad passphrase as a handshake
+            // timeout rather than a distinct error, so the best we can do
+            // without watching its state signals is treat SelectNetwork
+            // failures on a network with a psk set as "probably the
+            // passphrase", and anything else as a general association failure.
+            if password.is_some() {
+                WirelessError::PassphraseRejected
+            } else {
+                WirelessError::AssociationFailed(err.to_string())
+            }
+        })?;
+
+        network_id_from_path(&network)
+            .ok_or_else(|| WirelessError::AssociationFailed("malformed network path".to_string()))
+    }
+
+    fn network_path(&self, network_id: u32) -> OwnedObjectPath {
+        self.supplicant.network_path(network_id)
+    }
+
+    async fn set_radio_enabled(&self, enabled: bool) -> zbus::Result<()> {
+        let manager = networkmanager::NetworkManagerProxy::new(&self.connection).await?;
+        manager.set_wireless_enabled(enabled).await
+    }
+
+    async fn fetch_info(&self) -> zbus::Result<ScanResult> {
+        let device = networkmanager::WirelessDeviceProxy::builder(&self.connection)
+            .path(self.device_path.clone())?
+            .build()
+            .await?;
+
+        let ap_path = device.active_access_point().await?;
+        let ap = networkmanager::AccessPointProxy::builder(&self.connection)
+            .path(ap_path)?
+            .build()
+            .await?;
+        let info = ap.info().await?;
+
+        if info.ssid.is_empty() {
+            return Ok((String::new(), String::new(), WirelessSignalLevel::from_dbm(None), false));
+        }
+
+        let dbm = strength_to_dbm(info.strength);
+        Ok((
+            info.ssid,
+            format!("{dbm} dBm"),
+            WirelessSignalLevel::from_dbm(Some(dbm)),
+            info.is_secured,
+        ))
+    }
+}
+
+/// How often [`spawn_notification_stream`] emits `state_changed` by
+/// default, if the caller doesn't have a better interval in mind.
+pub const DEFAULT_NOTIFICATION_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Periodically emits [`WirelessBusInterface::state_changed`] with the
+/// current `info()`, on the given `interval`. The connection and signal
+/// context are built once up front rather than per tick, and the first
+/// emission happens immediately (via `tokio::time::interval`'s
+/// immediate-first-tick behavior) so subscribers get an initial state
+/// without waiting a full interval.
+pub async fn spawn_notification_stream(
+    interface: std::sync::Arc<WirelessBusInterface>,
+    connection: zbus::Connection,
+    object_path: OwnedObjectPath,
+    interval: Duration,
+) -> zbus::Result<()> {
+    let signal_ctxt = zbus::SignalContext::new(&connection, object_path)?;
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match interface.fetch_info().await {
+                Ok((ssid, signal, signal_level, is_secured)) => {
+                    if let Err(err) = WirelessBusInterface::state_changed(
+                        &signal_ctxt,
+                        ssid,
+                        signal,
+                        signal_level,
+                        is_secured,
+                    )
+                    .await
+                    {
+                        tracing::warn!(%err, "failed to emit wireless state_changed signal");
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(%err, "failed to fetch wireless info for notification stream");
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+#[dbus_interface(name = "com.mecha.Wireless")]
+impl WirelessBusInterface {
+    /// Returns `(ssid, signal, signal_level, is_secured)` for the
+    /// currently-associated access point. `signal` is kept as a raw string
+    /// for clients that predate `signal_level` - new clients should read
+    /// `signal_level` instead of re-deriving their own thresholds from it.
+    async fn info(&self) -> zbus::fdo::Result<ScanResult> {
+        self.fetch_info()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Lists nearby access points as `(ssid, signal, signal_level,
+    /// is_secured)`. Results are cached for [`SCAN_CACHE_TTL`] (10s) since
+    /// the settings-app networking screen can call this repeatedly as the
+    /// user navigates; pass `force = true` to always request a fresh
+    /// NetworkManager scan.
+    async fn scan(&self, force: bool) -> zbus::fdo::Result<Vec<ScanResult>> {
+        if !force {
+            let cache = self.scan_cache.lock().await;
+            if let Some(cached) = cache.as_ref() {
+                if cached.fetched_at.elapsed() < SCAN_CACHE_TTL {
+                    return Ok(cached.results.clone());
+                }
+            }
+        }
+
+        let device = networkmanager::WirelessDeviceProxy::builder(&self.connection)
+            .path(self.device_path.clone())
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?
+            .build()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+
+        device.request_scan(Default::default()).await.ok();
+
+        let ap_paths = device
+            .get_all_access_points()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+
+        let mut results = Vec::with_capacity(ap_paths.len());
+        for path in ap_paths {
+            let ap = networkmanager::AccessPointProxy::builder(&self.connection)
+                .path(path)
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?
+                .build()
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+            let info = ap
+                .info()
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+            if info.ssid.is_empty() {
+                continue;
+            }
+            let dbm = strength_to_dbm(info.strength);
+            results.push((
+                info.ssid,
+                format!("{dbm} dBm"),
+                WirelessSignalLevel::from_dbm(Some(dbm)),
+                info.is_secured,
+            ));
+        }
+
+        *self.scan_cache.lock().await = Some(ScanCache {
+            fetched_at: Instant::now(),
+            results: results.clone(),
+        });
+        Ok(results)
+    }
+
+    /// Connect to a broadcast SSID, returning the new network's id. An
+    /// empty `password` means the network is open - `Option<String>`
+    /// isn't a valid D-Bus method argument type, so this uses the same
+    /// empty-string-means-absent convention as the rest of this
+    /// interface's string arguments.
+    async fn connect(&self, ssid: String, password: String) -> zbus::fdo::Result<u32> {
+        let password = (!password.is_empty()).then_some(password.as_str());
+        self.connect_internal(&ssid, password, false)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Connect to an SSID that isn't broadcast, by setting `scan_ssid=1`
+    /// on the network block before selecting it. See [`Self::connect`] for
+    /// the `password` convention.
+    async fn connect_hidden(&self, ssid: String, password: String) -> zbus::fdo::Result<u32> {
+        let password = (!password.is_empty()).then_some(password.as_str());
+        self.connect_internal(&ssid, password, true)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Remove a known network entirely. Only disconnects first if
+    /// `network_id` is the currently active connection - forgetting a
+    /// saved-but-inactive network must not drop whatever the device is
+    /// actually connected to right now.
+    async fn disconnect(&self, network_id: u32) -> zbus::fdo::Result<()> {
+        let network = self.network_path(network_id);
+        if self.supplicant.current_network().await.ok().as_ref() == Some(&network) {
+            self.supplicant
+                .disconnect()
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        }
+        self.supplicant
+            .remove_network(&network)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Disconnect from whatever network is currently active without
+    /// forgetting it, unlike [`Self::disconnect`]. The settings-app
+    /// network details "Disconnect" button binds to this.
+    async fn disconnect_active(&self) -> zbus::fdo::Result<()> {
+        self.supplicant
+            .disconnect()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Lists saved networks as `(network_id, ssid, priority)`, for the
+    /// settings-app saved-networks screen.
+    async fn known_networks(&self) -> zbus::fdo::Result<Vec<(u32, String, i32)>> {
+        let paths = self
+            .supplicant
+            .list_networks()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+
+        let mut out = Vec::with_capacity(paths.len());
+        for path in paths {
+            let Some(id) = network_id_from_path(&path) else {
+                continue;
+            };
+            let properties = self
+                .supplicant
+                .network_properties(&path)
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+            let ssid = properties
+                .get("ssid")
+                .and_then(|value| String::try_from(value.clone()).ok())
+                .map(|ssid| ssid.trim_matches('"').to_string())
+                .unwrap_or_default();
+            let priority = properties
+                .get("priority")
+                .and_then(|value| i32::try_from(value.clone()).ok())
+                .unwrap_or(0);
+            out.push((id, ssid, priority));
+        }
+        Ok(out)
+    }
+
+    /// Sets a saved network's `priority` (wpa_supplicant prefers higher
+    /// values when several known networks are in range) and persists it
+    /// via `SaveConfig` so it survives a reboot.
+    async fn set_network_priority(&self, network_id: u32, priority: i32) -> zbus::fdo::Result<()> {
+        let network = self.network_path(network_id);
+        self.supplicant
+            .set_network_priority(&network, priority)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        self.supplicant
+            .save_config()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Whether the Wi-Fi radio is currently on, via NetworkManager's
+    /// `WirelessEnabled` property.
+    async fn enabled(&self) -> zbus::fdo::Result<bool> {
+        let manager = networkmanager::NetworkManagerProxy::new(&self.connection)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        manager
+            .wireless_enabled()
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Turns the Wi-Fi radio on. The settings-app networking screen's
+    /// wireless toggle binds to this.
+    async fn enable(&self) -> zbus::fdo::Result<()> {
+        self.set_radio_enabled(true)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Turns the Wi-Fi radio off.
+    async fn disable(&self) -> zbus::fdo::Result<()> {
+        self.set_radio_enabled(false)
+            .await
+            .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))
+    }
+
+    /// Emitted by [`spawn_notification_stream`] whenever it polls the
+    /// current connection state.
+    #[dbus_interface(signal)]
+    async fn state_changed(
+        signal_ctxt: &zbus::SignalContext<'_>,
+        ssid: String,
+        signal: String,
+        signal_level: WirelessSignalLevel,
+        is_secured: bool,
+    ) -> zbus::Result<()>;
+}
@@ -0,0 +1,9 @@
+mod supplicant;
+
+pub mod error;
+pub mod interface;
+pub mod signal;
+
+pub use error::WirelessError;
+pub use interface::{spawn_notification_stream, WirelessBusInterface, DEFAULT_NOTIFICATION_INTERVAL};
+pub use signal::WirelessSignalLevel;
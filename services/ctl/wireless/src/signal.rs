@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use zbus::zvariant::Type;
+
+/// Bucketed Wi-Fi signal strength, computed once on the server so every
+/// client (greeter, status bar, settings app) renders the same bars for the
+/// same signal instead of each picking its own thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[repr(u32)]
+pub enum WirelessSignalLevel {
+    NotFound,
+    Off,
+    Weak,
+    Low,
+    Good,
+    Strong,
+}
+
+impl WirelessSignalLevel {
+    /// `dbm` is a signed dBm reading; `None` means there's no associated
+    /// access point at all (distinct from a real but very weak signal).
+    pub fn from_dbm(dbm: Option<i32>) -> Self {
+        let Some(dbm) = dbm else {
+            return WirelessSignalLevel::NotFound;
+        };
+        if dbm >= -40 {
+            WirelessSignalLevel::Strong
+        } else if dbm >= -60 {
+            WirelessSignalLevel::Good
+        } else if dbm >= -80 {
+            WirelessSignalLevel::Low
+        } else if dbm > i32::MIN {
+            WirelessSignalLevel::Weak
+        } else {
+            WirelessSignalLevel::Off
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_access_point_is_not_found() {
+        assert_eq!(WirelessSignalLevel::from_dbm(None), WirelessSignalLevel::NotFound);
+    }
+
+    #[test]
+    fn strong_signal_above_negative_forty() {
+        assert_eq!(WirelessSignalLevel::from_dbm(Some(-35)), WirelessSignalLevel::Strong);
+    }
+
+    #[test]
+    fn weak_signal_below_negative_eighty() {
+        assert_eq!(WirelessSignalLevel::from_dbm(Some(-90)), WirelessSignalLevel::Weak);
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use zbus::zvariant::{OwnedObjectPath, Value};
+
+/// Thin wrapper around the `fi.w1.wpa_supplicant1.Interface` object for a
+/// single wireless interface - just the calls [`crate::WirelessBusInterface`]
+/// needs, not a general supplicant client.
+pub(crate) struct Supplicant {
+    connection: zbus::Connection,
+    interface_path: OwnedObjectPath,
+}
+
+impl Supplicant {
+    pub(crate) fn new(connection: zbus::Connection, interface_path: OwnedObjectPath) -> Self {
+        Self {
+            connection,
+            interface_path,
+        }
+    }
+
+    async fn proxy(&self) -> zbus::Result<zbus::Proxy<'_>> {
+        zbus::Proxy::new(
+            &self.connection,
+            "fi.w1.wpa_supplicant1",
+            self.interface_path.clone(),
+            "fi.w1.wpa_supplicant1.Interface",
+        )
+        .await
+    }
+
+    /// Adds a network block for `ssid` and returns its object path.
+    /// `hidden` sets `scan_ssid=1` so the network is actively probed for
+    /// rather than only matched against received beacons, which is
+    /// required to join an SSID that isn't broadcast.
+    pub(crate) async fn add_network(
+        &self,
+        ssid: &str,
+        password: Option<&str>,
+        hidden: bool,
+    ) -> zbus::Result<OwnedObjectPath> {
+        let proxy = self.proxy().await?;
+        let mut args: HashMap<&str, Value> = HashMap::new();
+        args.insert("ssid", Value::new(ssid));
+        match password {
+            Some(password) => {
+                args.insert("psk", Value::new(password));
+            }
+            None => {
+                args.insert("key_mgmt", Value::new("NONE"));
+            }
+        }
+        if hidden {
+            args.insert("scan_ssid", Value::new(1i32));
+        }
+        proxy.call("AddNetwork", &(args,)).await
+    }
+
+    pub(crate) async fn select_network(&self, network: &OwnedObjectPath) -> zbus::Result<()> {
+        let proxy = self.proxy().await?;
+        proxy.call("SelectNetwork", &(network,)).await
+    }
+
+    pub(crate) async fn remove_network(&self, network: &OwnedObjectPath) -> zbus::Result<()> {
+        let proxy = self.proxy().await?;
+        proxy.call("RemoveNetwork", &(network,)).await
+    }
+
+    pub(crate) async fn disconnect(&self) -> zbus::Result<()> {
+        let proxy = self.proxy().await?;
+        proxy.call("Disconnect", &()).await
+    }
+
+    pub(crate) async fn save_config(&self) -> zbus::Result<()> {
+        let proxy = self.proxy().await?;
+        proxy.call("SaveConfig", &()).await
+    }
+
+    pub(crate) async fn current_network(&self) -> zbus::Result<OwnedObjectPath> {
+        let proxy = self.proxy().await?;
+        proxy.get_property("CurrentNetwork").await
+    }
+
+    pub(crate) async fn list_networks(&self) -> zbus::Result<Vec<OwnedObjectPath>> {
+        let proxy = self.proxy().await?;
+        proxy.get_property("Networks").await
+    }
+
+    /// The `fi.w1.wpa_supplicant1.Network` object's `Properties` property -
+    /// the network block's config keys (`ssid`, `psk`, `priority`, ...).
+    pub(crate) async fn network_properties(
+        &self,
+        network: &OwnedObjectPath,
+    ) -> zbus::Result<HashMap<String, zbus::zvariant::OwnedValue>> {
+        let proxy = zbus::Proxy::new(
+            &self.connection,
+            "fi.w1.wpa_supplicant1",
+            network.clone(),
+            "fi.w1.wpa_supplicant1.Network",
+        )
+        .await?;
+        proxy.get_property("Properties").await
+    }
+
+    /// Builds the object path for a network id under this interface,
+    /// without needing a round trip through `Networks` to find it.
+    pub(crate) fn network_path(&self, network_id: u32) -> OwnedObjectPath {
+        OwnedObjectPath::try_from(format!("{}/Networks/{network_id}", self.interface_path.as_str()))
+            .expect("network id produces a valid object path")
+    }
+
+    pub(crate) async fn set_network_priority(
+        &self,
+        network: &OwnedObjectPath,
+        priority: i32,
+    ) -> zbus::Result<()> {
+        let proxy = zbus::Proxy::new(
+            &self.connection,
+            "fi.w1.wpa_supplicant1",
+            network.clone(),
+            "fi.w1.wpa_supplicant1.Network",
+        )
+        .await?;
+        let mut properties: HashMap<&str, Value> = HashMap::new();
+        properties.insert("priority", Value::new(priority));
+        proxy.call("SetProperties", &(properties,)).await
+    }
+}
+
+/// wpa_supplicant network object paths end in the network's numeric id
+/// (e.g. `/fi/w1/wpa_supplicant1/Interfaces/1/Networks/3`).
+pub(crate) fn network_id_from_path(path: &OwnedObjectPath) -> Option<u32> {
+    path.as_str().rsplit('/').next()?.parse().ok()
+}
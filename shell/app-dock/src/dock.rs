@@ -0,0 +1,173 @@
+use launcher::RunningApp;
+
+use crate::icon::resolve_icon;
+use crate::settings::App;
+
+/// A single tile rendered in the app dock: either a pinned app, a running
+/// app that isn't pinned, or both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockEntry {
+    pub app_id: String,
+    /// Set if this app is currently running, so clicking the tile
+    /// activates the toplevel rather than launching a new instance.
+    pub instance_id: Option<String>,
+    pub label: String,
+    pub icon: Option<String>,
+    pub is_pinned: bool,
+    pub is_focused: bool,
+}
+
+impl DockEntry {
+    pub fn is_running(&self) -> bool {
+        self.instance_id.is_some()
+    }
+}
+
+/// Builds the dock's tile list: every pinned app (running or not), plus any
+/// running app not already pinned, excluding `home`'s own `app_id` the way
+/// the status bar's running-apps module excludes its own tile.
+///
+/// `desktop_icon` looks up an `app_id`'s desktop-entry icon, used as the
+/// fallback when a pinned app has no (or an invalid) `icon` override, and
+/// as the only icon source for unpinned running apps.
+pub fn build_dock_entries(
+    pinned: &[App],
+    running: &[RunningApp],
+    focused_instance_id: Option<&str>,
+    home: Option<&str>,
+    desktop_icon: impl Fn(&str) -> Option<String>,
+) -> Vec<DockEntry> {
+    let mut entries = Vec::new();
+
+    for app in pinned {
+        if Some(app.app_id.as_str()) == home {
+            continue;
+        }
+        let running_instance = running.iter().find(|r| r.app_id == app.app_id);
+        let desktop_entry_icon = desktop_icon(&app.app_id);
+        entries.push(DockEntry {
+            app_id: app.app_id.clone(),
+            instance_id: running_instance.map(|r| r.instance_id.clone()),
+            label: app.alias.clone().unwrap_or_else(|| app.app_id.clone()),
+            icon: resolve_icon(app, desktop_entry_icon.as_deref()),
+            is_pinned: true,
+            is_focused: running_instance
+                .is_some_and(|r| Some(r.instance_id.as_str()) == focused_instance_id),
+        });
+    }
+
+    for app in running {
+        if Some(app.app_id.as_str()) == home {
+            continue;
+        }
+        if pinned.iter().any(|p| p.app_id == app.app_id) {
+            continue;
+        }
+        entries.push(DockEntry {
+            app_id: app.app_id.clone(),
+            instance_id: Some(app.instance_id.clone()),
+            label: app.app_id.clone(),
+            icon: desktop_icon(&app.app_id),
+            is_pinned: false,
+            is_focused: Some(app.instance_id.as_str()) == focused_instance_id,
+        });
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use launcher::WindowState;
+
+    fn running_app(app_id: &str, instance_id: &str) -> RunningApp {
+        RunningApp {
+            app_id: app_id.to_string(),
+            instance_id: instance_id.to_string(),
+            title: app_id.to_string(),
+            window_state: WindowState::default(),
+        }
+    }
+
+    fn pinned(app_id: &str) -> App {
+        App { app_id: app_id.to_string(), alias: None, icon: None }
+    }
+
+    fn no_desktop_icon(_app_id: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn pinned_apps_appear_even_when_not_running() {
+        let entries = build_dock_entries(&[pinned("terminal")], &[], None, None, no_desktop_icon);
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].is_running());
+    }
+
+    #[test]
+    fn running_apps_not_pinned_are_appended() {
+        let entries =
+            build_dock_entries(&[], &[running_app("browser", "browser-1")], None, None, no_desktop_icon);
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].is_pinned);
+        assert!(entries[0].is_running());
+    }
+
+    #[test]
+    fn pinned_and_running_merge_into_one_entry() {
+        let entries = build_dock_entries(
+            &[pinned("browser")],
+            &[running_app("browser", "browser-1")],
+            None,
+            None,
+            no_desktop_icon,
+        );
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].is_pinned);
+        assert!(entries[0].is_running());
+    }
+
+    #[test]
+    fn focused_instance_is_marked() {
+        let entries = build_dock_entries(
+            &[],
+            &[running_app("browser", "browser-1")],
+            Some("browser-1"),
+            None,
+            no_desktop_icon,
+        );
+        assert!(entries[0].is_focused);
+    }
+
+    #[test]
+    fn home_app_id_is_excluded() {
+        let entries = build_dock_entries(
+            &[pinned("launcher")],
+            &[running_app("launcher", "launcher-1")],
+            None,
+            Some("launcher"),
+            no_desktop_icon,
+        );
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn alias_is_used_as_the_label() {
+        let app = App { app_id: "term".to_string(), alias: Some("Terminal".to_string()), icon: None };
+        let entries = build_dock_entries(&[app], &[], None, None, no_desktop_icon);
+        assert_eq!(entries[0].label, "Terminal");
+    }
+
+    #[test]
+    fn unpinned_running_apps_use_the_desktop_entry_icon() {
+        let entries = build_dock_entries(
+            &[],
+            &[running_app("browser", "browser-1")],
+            None,
+            None,
+            |app_id| Some(format!("{app_id}.svg")),
+        );
+        assert_eq!(entries[0].icon, Some("browser.svg".to_string()));
+    }
+}
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use crate::settings::App;
+
+/// Resolves which icon path a dock tile should render: the app's explicit
+/// `icon` override if it's a `.png`/`.svg` file that actually exists,
+/// otherwise the desktop entry's icon. Logs a warning for a pinned app
+/// whose configured icon path is missing rather than silently showing a
+/// blank tile.
+pub fn resolve_icon(app: &App, desktop_entry_icon: Option<&str>) -> Option<String> {
+    if let Some(icon) = app.icon.as_deref().filter(|icon| !icon.is_empty()) {
+        if is_supported_icon_path(icon) && Path::new(icon).exists() {
+            return Some(icon.to_string());
+        }
+        tracing::warn!(
+            app_id = %app.app_id,
+            icon,
+            "pinned app's icon path does not exist or isn't a png/svg, falling back to desktop entry icon"
+        );
+    }
+    desktop_entry_icon.map(|icon| icon.to_string())
+}
+
+fn is_supported_icon_path(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    lower.ends_with(".png") || lower.ends_with(".svg")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(icon: Option<&str>) -> App {
+        App { app_id: "terminal".to_string(), alias: None, icon: icon.map(str::to_string) }
+    }
+
+    #[test]
+    fn falls_back_to_desktop_entry_icon_when_unset() {
+        assert_eq!(resolve_icon(&app(None), Some("/usr/share/icons/terminal.svg")), Some("/usr/share/icons/terminal.svg".to_string()));
+    }
+
+    #[test]
+    fn rejects_unsupported_extensions() {
+        assert_eq!(resolve_icon(&app(Some("/tmp/terminal.ico")), Some("fallback.svg")), Some("fallback.svg".to_string()));
+    }
+
+    #[test]
+    fn falls_back_when_configured_icon_does_not_exist() {
+        assert_eq!(
+            resolve_icon(&app(Some("/does/not/exist.png")), Some("fallback.svg")),
+            Some("fallback.svg".to_string())
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod dock;
+pub mod icon;
+pub mod settings;
+
+pub use dock::{build_dock_entries, DockEntry};
+pub use icon::resolve_icon;
+pub use settings::{App, AppDockSettings};
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+/// A pinned app-dock entry, as configured in `settings.yml`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct App {
+    pub app_id: String,
+    /// Shown as the dock tile's tooltip/label instead of the `app_id`,
+    /// if set.
+    #[serde(default)]
+    pub alias: Option<String>,
+    /// Path to a custom icon (png/svg), used instead of the desktop
+    /// entry's icon if set.
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+/// Settings loaded from `settings.yml` for the app dock.
+#[derive(Debug, Clone, Deserialize, Default, PartialEq)]
+pub struct AppDockSettings {
+    #[serde(default)]
+    pub pinned_apps: Vec<App>,
+    /// `app_id` of the dock's own home/launcher icon, excluded from the
+    /// running-window list so the dock doesn't show an entry for itself.
+    #[serde(default)]
+    pub home: Option<String>,
+}
+
+/// Where `settings.yml` lives; see [`config_path::find_config_path`].
+pub fn find_config_path() -> Option<std::path::PathBuf> {
+    config_path::find_config_path("settings.yml")
+}
@@ -0,0 +1,39 @@
+use battery::{BatteryReading, CriticalBatteryWarning};
+
+/// Greeter counterpart to `status_bar::BatteryModule`: the greeter runs its
+/// own `BatteryServiceHandle`, so it debounces the critical-level overlay
+/// independently rather than sharing state with the status bar process.
+#[derive(Debug, Clone, Default)]
+pub struct BatteryWarningState {
+    warning: CriticalBatteryWarning,
+}
+
+impl BatteryWarningState {
+    /// Feeds a new `level`/`status` poll. Returns `true` exactly when the
+    /// transient low-battery overlay should be shown for this update.
+    pub fn observe(&mut self, reading: BatteryReading) -> bool {
+        self.warning.observe(reading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fires_once_per_crossing() {
+        let mut state = BatteryWarningState::default();
+        assert!(!state.observe(BatteryReading {
+            level: 50,
+            is_charging: false
+        }));
+        assert!(state.observe(BatteryReading {
+            level: 5,
+            is_charging: false
+        }));
+        assert!(!state.observe(BatteryReading {
+            level: 4,
+            is_charging: false
+        }));
+    }
+}
@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+const EVDEV_LST_PATH: &str = "/usr/share/X11/xkb/rules/evdev.lst";
+const CONFIG_FILE_NAME: &str = "greeter.yml";
+
+/// One entry from the XKB rules `! layout` section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    pub code: String,
+    pub description: String,
+}
+
+/// Lists available layouts: `configured` if the device ships its own
+/// restricted list, otherwise whatever `evdev.lst` reports.
+pub fn list_available_layouts(configured: Option<&[KeyboardLayout]>) -> Vec<KeyboardLayout> {
+    if let Some(layouts) = configured {
+        return layouts.to_vec();
+    }
+    read_evdev_lst(Path::new(EVDEV_LST_PATH))
+}
+
+fn read_evdev_lst(path: &Path) -> Vec<KeyboardLayout> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    parse_evdev_lst(&contents)
+}
+
+/// Parses the `! layout` section of an XKB rules file, e.g.:
+/// ```text
+/// ! layout
+///   us           English (US)
+///   de           German
+/// ! variant
+///   ...
+/// ```
+/// Only the `! layout` section is relevant here; everything else (variants,
+/// models, options) is ignored.
+fn parse_evdev_lst(contents: &str) -> Vec<KeyboardLayout> {
+    let mut layouts = Vec::new();
+    let mut in_layout_section = false;
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('!') {
+            in_layout_section = trimmed == "! layout";
+            continue;
+        }
+        if !in_layout_section || trimmed.is_empty() {
+            continue;
+        }
+        if let Some((code, description)) = trimmed.split_once(char::is_whitespace) {
+            layouts.push(KeyboardLayout {
+                code: code.trim().to_string(),
+                description: description.trim().to_string(),
+            });
+        }
+    }
+    layouts
+}
+
+/// The last keyboard layout chosen in the greeter, persisted so it survives
+/// a reboot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyboardLayoutSettings {
+    pub selected: Option<String>,
+}
+
+impl KeyboardLayoutSettings {
+    pub fn load() -> Self {
+        let Some(path) = config_path::find_config_path(CONFIG_FILE_NAME) else {
+            return Self::default();
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        serde_yaml::from_str(&contents).unwrap_or_default()
+    }
+
+    /// Records `code` as the chosen layout and persists it. Applying the
+    /// layout to the layer-shell keyboard is the caller's job - this only
+    /// tracks which one was picked.
+    pub fn select(&mut self, code: &str) -> Result<(), String> {
+        self.selected = Some(code.to_string());
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let home = std::env::var_os("HOME").unwrap_or_default();
+        let path = std::path::PathBuf::from(home)
+            .join(".config/mechanix")
+            .join(CONFIG_FILE_NAME);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        let yaml = serde_yaml::to_string(self).map_err(|err| err.to_string())?;
+        fs::write(path, yaml).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_layout_section_and_ignores_others() {
+        let contents = "\
+! model
+  pc105        Generic 105-key PC
+! layout
+  us           English (US)
+  de           German
+! variant
+  intl         English (US, intl.)
+";
+        let layouts = parse_evdev_lst(contents);
+        assert_eq!(
+            layouts,
+            vec![
+                KeyboardLayout {
+                    code: "us".to_string(),
+                    description: "English (US)".to_string()
+                },
+                KeyboardLayout {
+                    code: "de".to_string(),
+                    description: "German".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn configured_list_takes_precedence_over_evdev_lst() {
+        let configured = vec![KeyboardLayout {
+            code: "us".to_string(),
+            description: "English (US)".to_string(),
+        }];
+        assert_eq!(list_available_layouts(Some(&configured)), configured);
+    }
+}
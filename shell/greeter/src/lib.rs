@@ -0,0 +1,11 @@
+pub mod battery_warning;
+pub mod keyboard_layout;
+pub mod login;
+pub mod pin_pad;
+pub mod sessions;
+
+pub use battery_warning::BatteryWarningState;
+pub use keyboard_layout::{list_available_layouts, KeyboardLayout, KeyboardLayoutSettings};
+pub use login::{submit_login, AuthSubmit, GreeterSettings, LoginHandlerMessage, LoginPageState, NO_REPLY_ERROR};
+pub use pin_pad::{PinPad, PinPadEvent};
+pub use sessions::{discover_sessions, SessionEntry};
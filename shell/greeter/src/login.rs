@@ -0,0 +1,280 @@
+use std::time::{Duration, Instant};
+
+use lock_screen::KeyboardModifiers;
+use tokio::sync::{mpsc, oneshot};
+
+/// Shown when the background task that talks to greetd has died or was
+/// dropped mid-request, so the reply channel closed without ever sending a
+/// result - distinct from greetd itself rejecting the credentials.
+pub const NO_REPLY_ERROR: &str = "no reply from login service";
+
+/// Shown by [`submit_login`] when `AuthState::is_locked` is true - the
+/// request never reaches greetd at all.
+pub const LOCKED_OUT_ERROR: &str = "too many failed attempts; try again shortly";
+
+use crate::keyboard_layout::KeyboardLayoutSettings;
+use crate::sessions::{discover_sessions, SessionEntry};
+
+/// Failed attempts allowed before the login form locks out.
+const MAX_ATTEMPTS: u32 = 5;
+/// How long the login form stays locked once `MAX_ATTEMPTS` is reached.
+const LOCKOUT_DURATION: Duration = Duration::from_secs(30);
+
+/// Values the login form can submit to the greeter's update loop.
+#[derive(Debug, Clone)]
+pub enum AuthSubmit {
+    Username(String),
+    Password(String),
+    /// The user picked a session/compositor from the session picker.
+    Session(String),
+    /// The user clicked the eye icon to reveal/hide the password field.
+    ToggleShowPassword,
+    Submit,
+}
+
+/// View state for the login page, separate from [`LoginHandlerMessage`]
+/// which drives the actual greetd conversation.
+#[derive(Debug, Clone, Default)]
+pub struct LoginPageState {
+    pub username: String,
+    pub password: String,
+    pub show_password: bool,
+    pub selected_session_cmd: Option<String>,
+    pub auth: AuthState,
+    /// Caps lock/num lock state from the layer-shell keyboard, shown as a
+    /// small indicator near the password/PIN field.
+    pub modifiers: KeyboardModifiers,
+}
+
+impl LoginPageState {
+    pub fn apply(&mut self, submit: &AuthSubmit) {
+        match submit {
+            AuthSubmit::Username(value) => self.username = value.clone(),
+            AuthSubmit::Password(value) => self.password = value.clone(),
+            AuthSubmit::Session(cmd) => self.selected_session_cmd = Some(cmd.clone()),
+            AuthSubmit::ToggleShowPassword => self.show_password = !self.show_password,
+            AuthSubmit::Submit => {}
+        }
+    }
+
+    /// Feed the result of a `LoginHandlerMessage::Login` round-trip into the
+    /// retry counter, locking the form out after too many failures.
+    pub fn record_auth_result(&mut self, result: &Result<(), String>) {
+        match result {
+            Ok(()) => self.auth.record_success(),
+            Err(err) => self.auth.record_failure(err.clone()),
+        }
+    }
+}
+
+/// Tracks failed login attempts and whether the form is currently locked
+/// out because of too many of them in a row.
+#[derive(Debug, Clone, Default)]
+pub struct AuthState {
+    pub failed_attempts: u32,
+    pub last_error: Option<String>,
+    locked_until: Option<Instant>,
+}
+
+impl AuthState {
+    pub fn record_success(&mut self) {
+        self.failed_attempts = 0;
+        self.last_error = None;
+        self.locked_until = None;
+    }
+
+    pub fn record_failure(&mut self, error: String) {
+        self.failed_attempts += 1;
+        self.last_error = Some(error);
+        if self.failed_attempts >= MAX_ATTEMPTS {
+            self.locked_until = Some(Instant::now() + LOCKOUT_DURATION);
+        }
+    }
+
+    /// Whether the login form should currently reject submissions.
+    pub fn is_locked(&self) -> bool {
+        self.locked_until
+            .map(|until| Instant::now() < until)
+            .unwrap_or(false)
+    }
+
+    pub fn remaining_lockout(&self) -> Option<Duration> {
+        self.locked_until
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+    }
+}
+
+/// Messages accepted by the background task that talks to greetd.
+#[derive(Debug)]
+pub enum LoginHandlerMessage {
+    Login {
+        username: String,
+        password: String,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    PasswordInput {
+        password: String,
+    },
+    /// Record which session command greetd's `StartSession` request should
+    /// launch once authentication succeeds.
+    SetSession {
+        cmd: String,
+        reply_to: oneshot::Sender<()>,
+    },
+}
+
+/// Greeter-wide settings surfaced to the GUI, including the list of
+/// sessions discovered on disk so the login screen can render a picker.
+#[derive(Debug, Clone)]
+pub struct GreeterSettings {
+    pub available_sessions: Vec<SessionEntry>,
+    pub selected_session_cmd: Option<String>,
+    /// Renders a numeric keypad instead of a password field, for kiosk
+    /// devices using PIN logins. Off by default so password-based installs
+    /// are unaffected.
+    pub pin_login_enabled: bool,
+    /// The last keyboard layout chosen in the greeter, if any.
+    pub keyboard_layout: Option<String>,
+    /// Passed to `ClockServiceHandle::run`/`set_format` for the greeter's
+    /// clock.
+    pub clock_format: clock::Clock,
+    /// IANA timezone name for the clock, e.g. `"America/New_York"`. Falls
+    /// back to UTC via [`clock::parse_timezone`] if unrecognized.
+    pub timezone: String,
+}
+
+impl GreeterSettings {
+    pub fn load() -> Self {
+        let available_sessions = discover_sessions();
+        let selected_session_cmd = available_sessions.first().map(|s| s.cmd.clone());
+        Self {
+            available_sessions,
+            selected_session_cmd,
+            pin_login_enabled: false,
+            keyboard_layout: KeyboardLayoutSettings::load().selected,
+            clock_format: clock::Clock::default(),
+            timezone: "UTC".to_string(),
+        }
+    }
+}
+
+/// Sends a `Login` request to the background greetd task and awaits its
+/// reply, translating a closed reply channel into [`NO_REPLY_ERROR`]
+/// instead of panicking on `.unwrap()` - the task can die (e.g. greetd's
+/// socket dropped) without ever sending a result.
+///
+/// Gated by `state.auth`: a request made while [`AuthState::is_locked`] is
+/// true is rejected with [`LOCKED_OUT_ERROR`] without ever reaching greetd,
+/// and every reply - success or failure - is fed back into `state.auth` via
+/// [`LoginPageState::record_auth_result`], so the lockout counter can't be
+/// forgotten by a caller that only remembers to submit.
+pub async fn submit_login(
+    handler: &mpsc::Sender<LoginHandlerMessage>,
+    state: &mut LoginPageState,
+) -> Result<(), String> {
+    if state.auth.is_locked() {
+        return Err(LOCKED_OUT_ERROR.to_string());
+    }
+
+    let (reply_to, reply_rx) = oneshot::channel();
+    let result = async {
+        handler
+            .send(LoginHandlerMessage::Login {
+                username: state.username.clone(),
+                password: state.password.clone(),
+                reply_to,
+            })
+            .await
+            .map_err(|_| NO_REPLY_ERROR.to_string())?;
+        reply_rx.await.map_err(|_| NO_REPLY_ERROR.to_string())?
+    }
+    .await;
+
+    state.record_auth_result(&result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn submit_login_returns_the_service_reply() {
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            if let Some(LoginHandlerMessage::Login { reply_to, .. }) = rx.recv().await {
+                let _ = reply_to.send(Err("bad password".to_string()));
+            }
+        });
+
+        let mut state = LoginPageState { username: "alice".to_string(), password: "wrong".to_string(), ..Default::default() };
+        let result = submit_login(&tx, &mut state).await;
+        assert_eq!(result, Err("bad password".to_string()));
+    }
+
+    #[tokio::test]
+    async fn submit_login_reports_no_reply_if_the_service_dies() {
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            // Drop the reply_to sender without ever replying, simulating
+            // the background task crashing mid-request.
+            let _ = rx.recv().await;
+        });
+
+        let mut state = LoginPageState { username: "alice".to_string(), password: "pw".to_string(), ..Default::default() };
+        let result = submit_login(&tx, &mut state).await;
+        assert_eq!(result, Err(NO_REPLY_ERROR.to_string()));
+    }
+
+    #[tokio::test]
+    async fn submit_login_records_failures_and_then_locks_itself_out() {
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            while let Some(LoginHandlerMessage::Login { reply_to, .. }) = rx.recv().await {
+                let _ = reply_to.send(Err("bad password".to_string()));
+            }
+        });
+
+        let mut state = LoginPageState { username: "alice".to_string(), password: "wrong".to_string(), ..Default::default() };
+        for _ in 0..MAX_ATTEMPTS {
+            let result = submit_login(&tx, &mut state).await;
+            assert_eq!(result, Err("bad password".to_string()));
+        }
+        assert!(state.auth.is_locked());
+
+        // The next attempt is rejected locally, without ever reaching the
+        // greetd task above.
+        let result = submit_login(&tx, &mut state).await;
+        assert_eq!(result, Err(LOCKED_OUT_ERROR.to_string()));
+    }
+
+    #[test]
+    fn toggle_show_password_flips_visibility() {
+        let mut state = LoginPageState::default();
+        assert!(!state.show_password);
+        state.apply(&AuthSubmit::ToggleShowPassword);
+        assert!(state.show_password);
+        state.apply(&AuthSubmit::ToggleShowPassword);
+        assert!(!state.show_password);
+    }
+
+    #[test]
+    fn locks_out_after_max_attempts() {
+        let mut auth = AuthState::default();
+        for _ in 0..MAX_ATTEMPTS - 1 {
+            auth.record_failure("bad password".to_string());
+            assert!(!auth.is_locked());
+        }
+        auth.record_failure("bad password".to_string());
+        assert!(auth.is_locked());
+    }
+
+    #[test]
+    fn success_resets_attempts() {
+        let mut auth = AuthState::default();
+        auth.record_failure("bad password".to_string());
+        auth.record_success();
+        assert_eq!(auth.failed_attempts, 0);
+        assert!(!auth.is_locked());
+    }
+}
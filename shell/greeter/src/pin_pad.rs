@@ -0,0 +1,90 @@
+use lock_screen::{PinEntry, PinSettings};
+
+use crate::login::AuthSubmit;
+
+/// Digit/backspace/submit events from the greeter's PIN keypad, kept
+/// separate from [`AuthSubmit`] since pressing a digit doesn't submit
+/// anything by itself - only [`PinPadEvent::Submit`] does.
+#[derive(Debug, Clone, Copy)]
+pub enum PinPadEvent {
+    Digit(char),
+    Backspace,
+    Submit,
+}
+
+/// Wraps the lock screen's [`PinEntry`] for the greeter's keypad fallback,
+/// turning a completed PIN into the same `AuthSubmit::Password` the
+/// text-entry login form produces so the rest of the auth flow doesn't
+/// need to know which one was used.
+#[derive(Debug, Clone)]
+pub struct PinPad {
+    entry: PinEntry,
+}
+
+impl PinPad {
+    pub fn new(settings: PinSettings) -> Self {
+        Self {
+            entry: PinEntry::new(settings),
+        }
+    }
+
+    /// Handles a keypad event, returning an [`AuthSubmit::Password`] only
+    /// when [`PinPadEvent::Submit`] is pressed with a complete PIN;
+    /// otherwise updates the entry in place and returns `None`.
+    pub fn handle(&mut self, event: PinPadEvent) -> Option<AuthSubmit> {
+        match event {
+            PinPadEvent::Digit(digit) => {
+                self.entry.push_digit(digit);
+                None
+            }
+            PinPadEvent::Backspace => {
+                self.entry.pop_digit();
+                None
+            }
+            PinPadEvent::Submit if self.entry.is_complete() => {
+                let submit = AuthSubmit::Password(self.entry.value().to_string());
+                self.entry.clear();
+                Some(submit)
+            }
+            PinPadEvent::Submit => None,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        self.entry.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submit_before_complete_is_ignored() {
+        let mut pad = PinPad::new(PinSettings { length: 4 });
+        pad.handle(PinPadEvent::Digit('1'));
+        assert!(pad.handle(PinPadEvent::Submit).is_none());
+    }
+
+    #[test]
+    fn submit_once_complete_yields_password_and_clears() {
+        let mut pad = PinPad::new(PinSettings { length: 4 });
+        for digit in "1234".chars() {
+            pad.handle(PinPadEvent::Digit(digit));
+        }
+        match pad.handle(PinPadEvent::Submit) {
+            Some(AuthSubmit::Password(password)) => assert_eq!(password, "1234"),
+            other => panic!("expected Password submit, got {other:?}"),
+        }
+        assert_eq!(pad.value(), "");
+    }
+
+    #[test]
+    fn backspace_removes_last_digit() {
+        let mut pad = PinPad::new(PinSettings { length: 4 });
+        pad.handle(PinPadEvent::Digit('1'));
+        pad.handle(PinPadEvent::Digit('2'));
+        pad.handle(PinPadEvent::Backspace);
+        assert_eq!(pad.value(), "1");
+    }
+}
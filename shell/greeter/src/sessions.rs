@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::Path;
+
+/// A selectable login session: a Wayland compositor or X session entry
+/// discovered from the system's session directories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionEntry {
+    pub name: String,
+    /// The command greetd should exec to start this session.
+    pub cmd: String,
+}
+
+const SESSION_DIRS: &[&str] = &["/usr/share/wayland-sessions", "/usr/share/xsessions"];
+
+/// Scans the well-known session directories for `.desktop` entries and
+/// returns them sorted by name. Missing directories are skipped silently -
+/// a headless/kiosk image may only ship one of the two.
+pub fn discover_sessions() -> Vec<SessionEntry> {
+    let mut sessions: Vec<SessionEntry> = SESSION_DIRS
+        .iter()
+        .flat_map(|dir| read_session_dir(Path::new(dir)))
+        .collect();
+    sessions.sort_by(|a, b| a.name.cmp(&b.name));
+    sessions
+}
+
+fn read_session_dir(dir: &Path) -> Vec<SessionEntry> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("desktop"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .filter_map(|contents| parse_desktop_entry(&contents))
+        .collect()
+}
+
+fn parse_desktop_entry(contents: &str) -> Option<SessionEntry> {
+    let mut name = None;
+    let mut cmd = None;
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("Name=") {
+            name = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("Exec=") {
+            cmd = Some(value.trim().to_string());
+        }
+    }
+    Some(SessionEntry {
+        name: name?,
+        cmd: cmd?,
+    })
+}
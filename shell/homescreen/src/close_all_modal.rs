@@ -0,0 +1,50 @@
+/// State for the "Close all N apps?" confirmation shown before sending
+/// `CloseAllApps`, so a stray tap on the running-apps view doesn't kill
+/// every open window.
+#[derive(Debug, Clone, Default)]
+pub struct CloseAllModalState {
+    running_count: Option<usize>,
+}
+
+impl CloseAllModalState {
+    pub fn is_open(&self) -> bool {
+        self.running_count.is_some()
+    }
+
+    /// Open the modal, or skip it entirely if there's nothing to close.
+    pub fn open_for(&mut self, running_count: usize) -> bool {
+        if running_count == 0 {
+            return false;
+        }
+        self.running_count = Some(running_count);
+        true
+    }
+
+    pub fn running_count(&self) -> Option<usize> {
+        self.running_count
+    }
+
+    pub fn close(&mut self) {
+        self.running_count = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonzero_count_opens_modal() {
+        let mut state = CloseAllModalState::default();
+        assert!(state.open_for(3));
+        assert!(state.is_open());
+        assert_eq!(state.running_count(), Some(3));
+    }
+
+    #[test]
+    fn zero_count_does_not_open_modal() {
+        let mut state = CloseAllModalState::default();
+        assert!(!state.open_for(0));
+        assert!(!state.is_open());
+    }
+}
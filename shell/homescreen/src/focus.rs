@@ -0,0 +1,164 @@
+/// Arrow-key/d-pad directions the homescreen grid can navigate in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Tracks which tile is focused for keyboard/d-pad navigation across the
+/// app grid, and the search query built up by typing while a tile has
+/// focus. The UI should add a `focused` CSS class to whichever tile's
+/// index matches [`GridFocus::current`], so the selection stays visible.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GridFocus {
+    current: Option<usize>,
+    search_query: String,
+}
+
+impl GridFocus {
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    pub fn search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    /// Moves focus one step in `direction` through `len` tiles laid out
+    /// `columns` wide. Moving past an edge is a no-op rather than
+    /// wrapping, matching how a `FlowBox` handles keyboard focus at its
+    /// edges. Nothing focused yet starts at the first tile.
+    pub fn move_focus(&mut self, len: usize, columns: usize, direction: Direction) {
+        self.current = move_focus(self.current, len, columns, direction);
+    }
+
+    /// Appends a typed character to the search query, so typing while the
+    /// grid has focus grabs input without the user clicking into the
+    /// search field first. Clears the tile focus since the visible grid
+    /// is about to change to the filtered results.
+    pub fn push_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.current = None;
+    }
+
+    pub fn backspace(&mut self) {
+        self.search_query.pop();
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search_query.clear();
+    }
+}
+
+fn move_focus(current: Option<usize>, len: usize, columns: usize, direction: Direction) -> Option<usize> {
+    if len == 0 || columns == 0 {
+        return None;
+    }
+    let Some(current) = current else {
+        return Some(0);
+    };
+
+    let next = match direction {
+        Direction::Right => {
+            let at_row_end = (current + 1) % columns == 0;
+            if at_row_end || current + 1 >= len {
+                current
+            } else {
+                current + 1
+            }
+        }
+        Direction::Left => {
+            if current % columns == 0 {
+                current
+            } else {
+                current - 1
+            }
+        }
+        Direction::Down => {
+            if current + columns < len {
+                current + columns
+            } else {
+                current
+            }
+        }
+        Direction::Up => {
+            if current >= columns {
+                current - columns
+            } else {
+                current
+            }
+        }
+    };
+    Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_focused_starts_at_the_first_tile() {
+        let mut focus = GridFocus::default();
+        focus.move_focus(6, 3, Direction::Right);
+        assert_eq!(focus.current(), Some(0));
+    }
+
+    #[test]
+    fn right_and_left_move_within_a_row() {
+        let mut focus = GridFocus::default();
+        focus.move_focus(6, 3, Direction::Right);
+        focus.move_focus(6, 3, Direction::Right);
+        assert_eq!(focus.current(), Some(1));
+        focus.move_focus(6, 3, Direction::Left);
+        assert_eq!(focus.current(), Some(0));
+    }
+
+    #[test]
+    fn right_does_not_cross_into_the_next_row() {
+        let mut focus = GridFocus::default();
+        focus.move_focus(6, 3, Direction::Right); // -> 0
+        focus.move_focus(6, 3, Direction::Right); // -> 1
+        focus.move_focus(6, 3, Direction::Right); // -> 2 (row end)
+        focus.move_focus(6, 3, Direction::Right); // no-op
+        assert_eq!(focus.current(), Some(2));
+    }
+
+    #[test]
+    fn down_and_up_move_a_full_row() {
+        let mut focus = GridFocus::default();
+        focus.move_focus(6, 3, Direction::Right); // -> 0
+        focus.move_focus(6, 3, Direction::Down); // -> 3
+        assert_eq!(focus.current(), Some(3));
+        focus.move_focus(6, 3, Direction::Up);
+        assert_eq!(focus.current(), Some(0));
+    }
+
+    #[test]
+    fn down_past_the_last_row_is_a_no_op() {
+        let mut focus = GridFocus::default();
+        focus.move_focus(4, 3, Direction::Down); // -> 0
+        focus.move_focus(4, 3, Direction::Down); // -> 3 (last row, only 1 tile)
+        focus.move_focus(4, 3, Direction::Down); // no-op, nothing below
+        assert_eq!(focus.current(), Some(3));
+    }
+
+    #[test]
+    fn typing_clears_tile_focus() {
+        let mut focus = GridFocus::default();
+        focus.move_focus(6, 3, Direction::Right);
+        focus.push_char('a');
+        assert_eq!(focus.current(), None);
+        assert_eq!(focus.search_query(), "a");
+    }
+
+    #[test]
+    fn backspace_removes_the_last_character() {
+        let mut focus = GridFocus::default();
+        focus.push_char('h');
+        focus.push_char('i');
+        focus.backspace();
+        assert_eq!(focus.search_query(), "h");
+    }
+}
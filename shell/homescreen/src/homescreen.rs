@@ -0,0 +1,234 @@
+use launcher::AppManagerMessage;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::close_all_modal::CloseAllModalState;
+use crate::focus::{Direction, GridFocus};
+use crate::osk;
+use crate::settings::HomescreenSettings;
+use crate::toast::{LaunchFailureReason, ToastState};
+
+/// Messages handled by the homescreen's update loop.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// The user tapped an app icon in the grid.
+    AppClicked(String),
+    /// The user long-pressed an app icon, toggling its favorite status.
+    AppLongPressed(String),
+    /// The user tapped "close all" in the running-apps view.
+    CloseAllRequested,
+    /// The user confirmed the "Close all N apps?" modal.
+    CloseAllConfirmed,
+    CloseAllCancelled,
+    /// An arrow key/d-pad press moved keyboard focus across the grid,
+    /// which is laid out `columns` wide with `len` tiles visible.
+    FocusMoved { len: usize, columns: usize, direction: Direction },
+    /// A character was typed while the grid had focus, so it's added to
+    /// the search query instead of requiring a tap into the search field.
+    SearchCharTyped(char),
+    SearchBackspace,
+    SearchCleared,
+    /// The search entry gained keyboard focus; show the on-screen keyboard.
+    SearchFocusGained,
+    /// The search entry lost focus, or the grid was scrolled; dismiss it.
+    SearchFocusLost,
+    /// The "Couldn't launch <app>" toast finished its timeout.
+    ToastDismissed,
+}
+
+/// The homescreen shell surface: the app grid shown on the shell's root view.
+pub struct Homescreen {
+    app_manager_tx: mpsc::Sender<AppManagerMessage>,
+    close_all_modal: CloseAllModalState,
+    grid_focus: GridFocus,
+    settings: HomescreenSettings,
+    toast: ToastState,
+}
+
+impl Homescreen {
+    pub fn new(app_manager_tx: mpsc::Sender<AppManagerMessage>) -> Self {
+        Self {
+            app_manager_tx,
+            close_all_modal: CloseAllModalState::default(),
+            grid_focus: GridFocus::default(),
+            settings: HomescreenSettings::load(),
+            toast: ToastState::default(),
+        }
+    }
+
+    pub fn close_all_modal(&self) -> &CloseAllModalState {
+        &self.close_all_modal
+    }
+
+    /// The active "Couldn't launch <app>" toast message, if any.
+    pub fn toast(&self) -> &ToastState {
+        &self.toast
+    }
+
+    /// Keyboard/d-pad focus state for the app grid and its search query.
+    pub fn grid_focus(&self) -> &GridFocus {
+        &self.grid_focus
+    }
+
+    /// Favorited app ids, in the order they should be pinned above the grid.
+    pub fn favorites(&self) -> &[String] {
+        &self.settings.favorites
+    }
+
+    /// Recently-launched app ids, most-recent-first.
+    pub fn recent_apps(&self) -> &[String] {
+        &self.settings.recent_apps
+    }
+
+    pub async fn update(&mut self, message: Message) {
+        match message {
+            Message::AppClicked(app_id) => {
+                self.handle_app_clicked(app_id).await;
+            }
+            Message::AppLongPressed(app_id) => {
+                if let Err(err) = self.settings.toggle_favorite(&app_id) {
+                    tracing::warn!(%app_id, %err, "failed to persist favorite toggle");
+                }
+            }
+            Message::CloseAllRequested => {
+                self.handle_close_all_requested().await;
+            }
+            Message::CloseAllConfirmed => {
+                self.handle_close_all_confirmed().await;
+            }
+            Message::CloseAllCancelled => {
+                self.close_all_modal.close();
+            }
+            Message::FocusMoved { len, columns, direction } => {
+                self.grid_focus.move_focus(len, columns, direction);
+            }
+            Message::SearchCharTyped(c) => {
+                self.grid_focus.push_char(c);
+            }
+            Message::SearchBackspace => {
+                self.grid_focus.backspace();
+            }
+            Message::SearchCleared => {
+                self.grid_focus.clear_search();
+            }
+            Message::SearchFocusGained => {
+                osk::show(&self.settings.osk_command);
+            }
+            Message::SearchFocusLost => {
+                osk::hide(&self.settings.osk_command);
+            }
+            Message::ToastDismissed => {
+                self.toast.dismiss();
+            }
+        }
+    }
+
+    /// Count the running instances and open the confirmation modal, rather
+    /// than closing anything yet.
+    async fn handle_close_all_requested(&mut self) {
+        let (reply_to, rx) = oneshot::channel();
+        if self
+            .app_manager_tx
+            .send(AppManagerMessage::ListRunning { reply_to })
+            .await
+            .is_err()
+        {
+            return;
+        }
+        let running_count = rx.await.map(|apps| apps.len()).unwrap_or(0);
+        self.close_all_modal.open_for(running_count);
+    }
+
+    async fn handle_close_all_confirmed(&mut self) {
+        self.close_all_modal.close();
+        let (reply_to, rx) = oneshot::channel();
+        if self
+            .app_manager_tx
+            .send(AppManagerMessage::CloseAllApps { reply_to })
+            .await
+            .is_err()
+        {
+            tracing::warn!("app manager service unavailable, could not close all apps");
+            return;
+        }
+        if let Ok(closed) = rx.await {
+            tracing::info!(closed, "closed all running apps");
+        }
+    }
+
+    /// If `app_id` already has a running instance, activate it instead of
+    /// spawning a duplicate; otherwise launch a fresh instance.
+    async fn handle_app_clicked(&mut self, app_id: String) {
+        let (reply_to, is_running_rx) = oneshot::channel();
+        if self
+            .app_manager_tx
+            .send(AppManagerMessage::IsRunning {
+                app_id: app_id.clone(),
+                reply_to,
+            })
+            .await
+            .is_err()
+        {
+            tracing::warn!("app manager service unavailable, launching {app_id} directly");
+            self.launch_app(&app_id).await;
+            return;
+        }
+
+        let is_running = is_running_rx.await.unwrap_or(false);
+        if is_running {
+            let (reply_to, activate_rx) = oneshot::channel();
+            let _ = self
+                .app_manager_tx
+                .send(AppManagerMessage::ActivateApp {
+                    app_id: app_id.clone(),
+                    reply_to,
+                })
+                .await;
+            match activate_rx.await {
+                Ok(Err(err)) => {
+                    tracing::warn!(%app_id, %err, "failed to activate running app, launching fresh instance");
+                    self.launch_app(&app_id).await;
+                }
+                Ok(Ok(())) => self.record_launch(&app_id),
+                Err(_) => {}
+            }
+        } else {
+            self.launch_app(&app_id).await;
+        }
+    }
+
+    /// Sends `LaunchApp` and records the launch on success, surfacing a
+    /// toast that distinguishes an unreachable app manager from a launch
+    /// the service itself rejected.
+    async fn launch_app(&mut self, app_id: &str) {
+        let (reply_to, rx) = oneshot::channel();
+        if self
+            .app_manager_tx
+            .send(AppManagerMessage::LaunchApp {
+                app_id: app_id.to_string(),
+                reply_to,
+            })
+            .await
+            .is_err()
+        {
+            self.toast.show_launch_failure(app_id, LaunchFailureReason::AppManagerUnavailable);
+            return;
+        }
+
+        match rx.await {
+            Ok(Ok(())) => self.record_launch(app_id),
+            Ok(Err(err)) => {
+                tracing::warn!(%app_id, %err, "app manager rejected the launch");
+                self.toast.show_launch_failure(app_id, LaunchFailureReason::LaunchFailed);
+            }
+            Err(_) => {
+                self.toast.show_launch_failure(app_id, LaunchFailureReason::AppManagerUnavailable);
+            }
+        }
+    }
+
+    fn record_launch(&mut self, app_id: &str) {
+        if let Err(err) = self.settings.record_launch(app_id) {
+            tracing::warn!(%app_id, %err, "failed to persist recent app launch");
+        }
+    }
+}
@@ -0,0 +1,22 @@
+pub mod close_all_modal;
+pub mod focus;
+pub mod homescreen;
+pub mod osk;
+pub mod search;
+pub mod settings;
+pub mod theme;
+pub mod toast;
+pub mod ui;
+
+pub use close_all_modal::CloseAllModalState;
+pub use focus::{Direction, GridFocus};
+pub use homescreen::{Homescreen, Message};
+pub use osk::{hide as hide_osk, show as show_osk};
+pub use search::search_apps;
+pub use settings::{AppFilterSettings, GroupingMode, HomescreenSettings, SortMode};
+pub use theme::{ThemeError, ThemeWatcher};
+pub use toast::{LaunchFailureReason, ToastState};
+pub use ui::{
+    build_recent_row, filter_apps, generate_apps_ui, group_into_sections, partition_favorites, sort_apps, AppEntry,
+    AppSection, AppUiModel,
+};
@@ -0,0 +1,23 @@
+use std::process::{Command, Stdio};
+
+/// Shows the on-screen keyboard by running `<command> show`, e.g.
+/// `squeekboard-toggle show` or a `wtype`-backed wrapper script. Best
+/// effort: a missing or failing OSK binary shouldn't block typing on
+/// devices that have a physical keyboard, so failures are only logged.
+pub fn show(command: &str) {
+    run(command, "show");
+}
+
+/// Dismisses the on-screen keyboard by running `<command> hide`.
+pub fn hide(command: &str) {
+    run(command, "hide");
+}
+
+fn run(command: &str, arg: &str) {
+    if command.is_empty() {
+        return;
+    }
+    if let Err(err) = Command::new(command).arg(arg).stdout(Stdio::null()).stderr(Stdio::null()).spawn() {
+        tracing::warn!(%command, %arg, %err, "failed to run on-screen-keyboard command");
+    }
+}
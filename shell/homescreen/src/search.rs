@@ -0,0 +1,67 @@
+use crate::ui::AppUiModel;
+
+/// Filters `apps` by `query`, matching the app's name first. If nothing
+/// matches by name, falls back to matching against the desktop entry's
+/// `Exec=` line, so typing the binary name (e.g. "nautilus") still finds
+/// an app whose display name is something else entirely (e.g. "Files").
+/// An empty query returns every app, unfiltered.
+pub fn search_apps(apps: &[AppUiModel], query: &str) -> Vec<AppUiModel> {
+    if query.is_empty() {
+        return apps.to_vec();
+    }
+
+    let query = query.to_lowercase();
+    let by_name: Vec<AppUiModel> = apps
+        .iter()
+        .filter(|app| app.name.to_lowercase().contains(&query))
+        .cloned()
+        .collect();
+
+    if !by_name.is_empty() {
+        return by_name;
+    }
+
+    apps.iter()
+        .filter(|app| app.exec.to_lowercase().contains(&query))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(app_id: &str, name: &str, exec: &str) -> AppUiModel {
+        AppUiModel {
+            app_id: app_id.to_string(),
+            name: name.to_string(),
+            icon_path: String::new(),
+            categories: Vec::new(),
+            exec: exec.to_string(),
+        }
+    }
+
+    #[test]
+    fn empty_query_returns_every_app() {
+        let apps = vec![app("a", "Alpha", "/bin/alpha")];
+        assert_eq!(search_apps(&apps, ""), apps);
+    }
+
+    #[test]
+    fn matches_by_name_case_insensitively() {
+        let apps = vec![app("files", "Files", "/usr/bin/nautilus")];
+        assert_eq!(search_apps(&apps, "fil"), apps);
+    }
+
+    #[test]
+    fn falls_back_to_exec_when_name_does_not_match() {
+        let apps = vec![app("files", "Files", "/usr/bin/nautilus %U")];
+        assert_eq!(search_apps(&apps, "nautilus"), apps);
+    }
+
+    #[test]
+    fn no_match_in_either_returns_empty() {
+        let apps = vec![app("files", "Files", "/usr/bin/nautilus")];
+        assert!(search_apps(&apps, "calculator").is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+const CONFIG_FILE_NAME: &str = "homescreen.yml";
+
+/// How `apps`/`filtered_apps` are ordered for display.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    /// Case-insensitive by display name. The default: stable and
+    /// predictable regardless of launch history.
+    #[default]
+    Alphabetical,
+    /// Most launches first, via [`HomescreenSettings::launch_counts`].
+    MostUsed,
+    /// Most-recently-launched first, via [`HomescreenSettings::recent_apps`].
+    RecentlyUsed,
+}
+
+/// Whether the app grid is one flat list or grouped into `Categories=`
+/// sections.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupingMode {
+    #[default]
+    Flat,
+    Categorized,
+}
+
+/// How many app ids [`HomescreenSettings::record_launch`] keeps in
+/// `recent_apps`.
+const RECENT_APPS_CAP: usize = 8;
+
+/// Controls which apps appear in the grid, independent of a desktop
+/// entry's own `NoDisplay=`/`Hidden=` flags. `exclude` is applied either
+/// way; `include_only` additionally drops anything not named in `include`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppFilterSettings {
+    #[serde(default)]
+    pub include_only: bool,
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Homescreen-specific settings, loaded from `homescreen.yml` via
+/// [`config_path::find_config_path`] the same way `launcher::settings` does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HomescreenSettings {
+    /// App ids pinned to the row above the scrollable grid, in display
+    /// order.
+    #[serde(default)]
+    pub favorites: Vec<String>,
+    #[serde(default)]
+    pub grouping: GroupingMode,
+    /// Most-recently-launched app ids, most-recent-first, capped at
+    /// [`RECENT_APPS_CAP`].
+    #[serde(default)]
+    pub recent_apps: Vec<String>,
+    #[serde(default)]
+    pub app_filter: AppFilterSettings,
+    /// Command run as `<osk_command> show`/`<osk_command> hide` to toggle
+    /// the on-screen keyboard when the search entry gains/loses focus,
+    /// e.g. `squeekboard-toggle`. Empty disables OSK integration, for
+    /// devices with a physical keyboard.
+    #[serde(default = "default_osk_command")]
+    pub osk_command: String,
+    /// How the grid orders apps. Defaults to [`SortMode::Alphabetical`].
+    #[serde(default)]
+    pub sort: SortMode,
+    /// Launch counts per app_id, behind [`SortMode::MostUsed`].
+    #[serde(default)]
+    pub launch_counts: HashMap<String, u32>,
+    /// Path to the stylesheet applied at startup and hot-reloaded by
+    /// [`crate::theme::ThemeWatcher`]. `None` uses the view layer's
+    /// built-in default styling.
+    #[serde(default)]
+    pub theme_css_path: Option<PathBuf>,
+}
+
+fn default_osk_command() -> String {
+    "squeekboard-toggle".to_string()
+}
+
+impl Default for HomescreenSettings {
+    fn default() -> Self {
+        Self {
+            favorites: Vec::new(),
+            grouping: GroupingMode::default(),
+            recent_apps: Vec::new(),
+            app_filter: AppFilterSettings::default(),
+            osk_command: default_osk_command(),
+            sort: SortMode::default(),
+            launch_counts: HashMap::new(),
+            theme_css_path: None,
+        }
+    }
+}
+
+impl HomescreenSettings {
+    /// Loads settings from disk, falling back to defaults if no config
+    /// file is present or it fails to parse.
+    pub fn load() -> Self {
+        let Some(path) = config_path::find_config_path(CONFIG_FILE_NAME) else {
+            return Self::default();
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        serde_yaml::from_str(&contents).unwrap_or_default()
+    }
+
+    /// Writes settings back to the user config dir, creating it if
+    /// necessary. Used after a long-press toggles favorite status.
+    pub fn save(&self) -> Result<(), String> {
+        let path = user_config_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        let yaml = serde_yaml::to_string(self).map_err(|err| err.to_string())?;
+        fs::write(path, yaml).map_err(|err| err.to_string())
+    }
+
+    /// Toggles `app_id`'s favorite status and persists the result.
+    pub fn toggle_favorite(&mut self, app_id: &str) -> Result<(), String> {
+        match self.favorites.iter().position(|id| id == app_id) {
+            Some(index) => {
+                self.favorites.remove(index);
+            }
+            None => self.favorites.push(app_id.to_string()),
+        }
+        self.save()
+    }
+
+    /// Moves `app_id` to the front of `recent_apps`, capped at
+    /// [`RECENT_APPS_CAP`], bumps its launch count, and persists the result.
+    pub fn record_launch(&mut self, app_id: &str) -> Result<(), String> {
+        push_recent(&mut self.recent_apps, app_id, RECENT_APPS_CAP);
+        *self.launch_counts.entry(app_id.to_string()).or_insert(0) += 1;
+        self.save()
+    }
+}
+
+/// Pure helper behind [`HomescreenSettings::record_launch`]: removes any
+/// existing occurrence of `app_id`, inserts it at the front, then
+/// truncates to `cap`.
+fn push_recent(recent: &mut Vec<String>, app_id: &str, cap: usize) {
+    recent.retain(|id| id != app_id);
+    recent.insert(0, app_id.to_string());
+    recent.truncate(cap);
+}
+
+fn user_config_path() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_default();
+    PathBuf::from(home).join(".config/mechanix").join(CONFIG_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_adds_then_removes_an_app_id() {
+        std::env::set_var("HOME", std::env::temp_dir());
+        let mut settings = HomescreenSettings::default();
+        settings.toggle_favorite("app.one").unwrap();
+        assert_eq!(settings.favorites, vec!["app.one".to_string()]);
+        settings.toggle_favorite("app.one").unwrap();
+        assert!(settings.favorites.is_empty());
+    }
+
+    #[test]
+    fn push_recent_moves_relaunched_app_to_front() {
+        let mut recent = vec!["a".to_string(), "b".to_string()];
+        push_recent(&mut recent, "b", 8);
+        assert_eq!(recent, vec!["b".to_string(), "a".to_string()]);
+    }
+
+    #[test]
+    fn push_recent_truncates_to_cap() {
+        let mut recent = vec!["a".to_string(), "b".to_string()];
+        push_recent(&mut recent, "c", 2);
+        assert_eq!(recent, vec!["c".to_string(), "a".to_string()]);
+    }
+
+    #[test]
+    fn default_app_filter_allows_everything() {
+        assert_eq!(AppFilterSettings::default(), AppFilterSettings {
+            include_only: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+        });
+    }
+}
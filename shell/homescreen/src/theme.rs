@@ -0,0 +1,117 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc as std_mpsc;
+use std::time::{Duration, Instant};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Minimum time between two reloads sent down the channel, coalescing the
+/// burst of modify events a single save can produce (e.g. an editor that
+/// writes via a temp file and renames it over the original).
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Error from [`read_css`]. There's no CSS parser in this crate, so
+/// `Unbalanced` is a cheap sanity check (mismatched `{`/`}`) rather than a
+/// real syntax check - good enough to catch a save mid-edit and keep the
+/// previously loaded stylesheet instead of handing a half-written file to
+/// the view layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeError {
+    Io(String),
+    Unbalanced,
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(message) => write!(f, "failed to read theme CSS: {message}"),
+            ThemeError::Unbalanced => write!(f, "theme CSS has mismatched braces"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+fn read_css(path: &Path) -> Result<String, ThemeError> {
+    let css = std::fs::read_to_string(path).map_err(|err| ThemeError::Io(err.to_string()))?;
+    if brace_balance(&css) != 0 {
+        return Err(ThemeError::Unbalanced);
+    }
+    Ok(css)
+}
+
+fn brace_balance(css: &str) -> i32 {
+    css.chars().fold(0, |balance, c| match c {
+        '{' => balance + 1,
+        '}' => balance - 1,
+        _ => balance,
+    })
+}
+
+/// Watches the theme CSS file for changes and sends its freshly-read
+/// contents down the returned channel, so theme authors can iterate
+/// without restarting the shell. Pairs with
+/// `launcher::settings::SettingsWatcher` but is specific to the
+/// stylesheet; a reload that fails [`read_css`]'s sanity check is logged
+/// and dropped, leaving the previously sent stylesheet in place. Drop the
+/// returned [`ThemeWatcher`] to stop watching.
+pub struct ThemeWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl ThemeWatcher {
+    pub fn watch(path: PathBuf) -> notify::Result<(Self, std_mpsc::Receiver<String>)> {
+        let (tx, rx) = std_mpsc::channel();
+        let mut last_sent = None::<Instant>;
+        let watch_path = path.clone();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else { return };
+            if !event.kind.is_modify() {
+                return;
+            }
+            if last_sent.is_some_and(|at| at.elapsed() < DEBOUNCE) {
+                return;
+            }
+            match read_css(&path) {
+                Ok(css) => {
+                    last_sent = Some(Instant::now());
+                    let _ = tx.send(css);
+                }
+                Err(err) => {
+                    tracing::warn!(%err, path = %path.display(), "keeping previous theme: failed to reload CSS");
+                }
+            }
+        })?;
+        watcher.watch(&watch_path, RecursiveMode::NonRecursive)?;
+        Ok((Self { _watcher: watcher }, rx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balanced_css_reads_cleanly() {
+        let dir = std::env::temp_dir().join(format!("theme-test-{:?}", std::thread::current().id()));
+        std::fs::write(&dir, ".tile { color: red; }").unwrap();
+        assert_eq!(read_css(&dir).unwrap(), ".tile { color: red; }");
+        std::fs::remove_file(&dir).unwrap();
+    }
+
+    #[test]
+    fn unbalanced_css_is_rejected() {
+        let dir = std::env::temp_dir().join(format!("theme-test-unbalanced-{:?}", std::thread::current().id()));
+        std::fs::write(&dir, ".tile { color: red;").unwrap();
+        assert_eq!(read_css(&dir), Err(ThemeError::Unbalanced));
+        std::fs::remove_file(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        assert!(matches!(
+            read_css(Path::new("/nonexistent/theme.css")),
+            Err(ThemeError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+/// How long a toast stays visible before the UI should treat it as
+/// dismissed.
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Why an app launch failed, so the toast message can tell apart "the
+/// app manager service isn't reachable" from "it rejected the launch".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchFailureReason {
+    AppManagerUnavailable,
+    LaunchFailed,
+}
+
+/// Drives the transient "Couldn't launch <app>" banner shown after a
+/// failed launch from the homescreen grid. Separate from [`crate::Homescreen`]
+/// so the view layer can poll `message` on every frame without mutating
+/// launch-handling state.
+#[derive(Debug, Clone, Default)]
+pub struct ToastState {
+    shown_at: Option<Instant>,
+    message: String,
+}
+
+impl ToastState {
+    /// Shows a launch-failure toast for `app_name`.
+    pub fn show_launch_failure(&mut self, app_name: &str, reason: LaunchFailureReason) {
+        self.message = match reason {
+            LaunchFailureReason::AppManagerUnavailable => {
+                format!("Couldn't launch {app_name} — app manager unavailable")
+            }
+            LaunchFailureReason::LaunchFailed => format!("Couldn't launch {app_name}"),
+        };
+        self.shown_at = Some(Instant::now());
+    }
+
+    pub fn dismiss(&mut self) {
+        self.shown_at = None;
+    }
+
+    /// The active toast's message, or `None` once [`TOAST_DURATION`] has
+    /// passed since it was shown.
+    pub fn message(&self) -> Option<&str> {
+        self.shown_at
+            .filter(|shown_at| shown_at.elapsed() < TOAST_DURATION)
+            .map(|_| self.message.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_message_before_a_failure_is_shown() {
+        assert_eq!(ToastState::default().message(), None);
+    }
+
+    #[test]
+    fn app_manager_unavailable_message_names_the_app() {
+        let mut toast = ToastState::default();
+        toast.show_launch_failure("Terminal", LaunchFailureReason::AppManagerUnavailable);
+        assert_eq!(toast.message(), Some("Couldn't launch Terminal — app manager unavailable"));
+    }
+
+    #[test]
+    fn launch_failed_message_names_the_app() {
+        let mut toast = ToastState::default();
+        toast.show_launch_failure("Terminal", LaunchFailureReason::LaunchFailed);
+        assert_eq!(toast.message(), Some("Couldn't launch Terminal"));
+    }
+
+    #[test]
+    fn dismiss_clears_the_message() {
+        let mut toast = ToastState::default();
+        toast.show_launch_failure("Terminal", LaunchFailureReason::LaunchFailed);
+        toast.dismiss();
+        assert_eq!(toast.message(), None);
+    }
+}
@@ -0,0 +1,403 @@
+/// A single app tile as rendered by the homescreen grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppUiModel {
+    pub app_id: String,
+    pub name: String,
+    /// Always resolvable: either an absolute path that exists, a theme
+    /// icon name, or [`desktop_entry::DEFAULT_ICON`] - never empty, so a
+    /// tile is never rendered blank. See [`resolve_icon`].
+    pub icon_path: String,
+    pub categories: Vec<String>,
+    /// The desktop entry's `Exec=` line, kept around so search can fall
+    /// back to matching it when the query doesn't match the app's name.
+    pub exec: String,
+}
+
+pub struct AppEntry {
+    pub app_id: String,
+    pub name: String,
+    pub icon_path: Option<String>,
+    pub categories: Vec<String>,
+    pub exec: String,
+}
+
+impl From<desktop_entry::DesktopEntry> for AppEntry {
+    /// `desktop_entry::discover_apps` already drops `NoDisplay`/`Hidden`
+    /// entries, so every `DesktopEntry` reaching here is meant to be shown.
+    fn from(entry: desktop_entry::DesktopEntry) -> Self {
+        Self {
+            app_id: entry.app_id,
+            name: entry.name,
+            icon_path: entry.icon,
+            categories: entry.categories,
+            exec: entry.exec,
+        }
+    }
+}
+
+/// Applies [`crate::settings::AppFilterSettings`] to the discovered app
+/// list before it's rendered: `exclude` always drops a listed app_id, and
+/// when `include_only` is set, anything not listed in `include` is
+/// dropped too.
+pub fn filter_apps(apps: Vec<AppEntry>, filter: &crate::settings::AppFilterSettings) -> Vec<AppEntry> {
+    apps.into_iter()
+        .filter(|app| {
+            if filter.exclude.contains(&app.app_id) {
+                return false;
+            }
+            !filter.include_only || filter.include.contains(&app.app_id)
+        })
+        .collect()
+}
+
+const MAX_LEN_APP_NAME: usize = 15;
+
+/// Builds the list of tiles shown in the app grid, truncating overly long
+/// names to [`MAX_LEN_APP_NAME`] chars (not bytes) and appending an ellipsis so we
+/// never slice in the middle of a multi-byte UTF-8 codepoint.
+pub fn generate_apps_ui(apps: &[AppEntry]) -> Vec<AppUiModel> {
+    apps.iter()
+        .map(|app| AppUiModel {
+            app_id: app.app_id.clone(),
+            name: truncate_with_ellipsis(&app.name, MAX_LEN_APP_NAME),
+            icon_path: resolve_icon(app.icon_path.as_deref()),
+            categories: app.categories.clone(),
+            exec: app.exec.clone(),
+        })
+        .collect()
+}
+
+/// Resolves the icon a tile should render: an absolute path (as loaded via
+/// `gio::File` rather than the icon theme) if it exists on disk, otherwise
+/// the value as a theme icon name, falling back to
+/// [`desktop_entry::DEFAULT_ICON`] when there's nothing usable - a missing
+/// icon path or no `Icon=` at all - so the tile never renders blank.
+fn resolve_icon(icon_path: Option<&str>) -> String {
+    match icon_path {
+        Some(icon) if icon.starts_with('/') => {
+            if std::path::Path::new(icon).exists() {
+                icon.to_string()
+            } else {
+                tracing::warn!(icon, "app icon path does not exist, falling back to default icon");
+                desktop_entry::DEFAULT_ICON.to_string()
+            }
+        }
+        Some(icon) if !icon.is_empty() => icon.to_string(),
+        _ => desktop_entry::DEFAULT_ICON.to_string(),
+    }
+}
+
+/// Label for entries with no `Categories=`, shown as the catch-all
+/// section in categorized mode.
+pub const UNCATEGORIZED_SECTION: &str = "All";
+
+/// A labeled group of tiles for the categorized homescreen grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppSection {
+    pub name: String,
+    pub apps: Vec<AppUiModel>,
+}
+
+/// Groups `apps` by their first declared category, alphabetically by
+/// section name, with entries that declare none (or only unparseable
+/// entries) collected into [`UNCATEGORIZED_SECTION`] at the end. An app
+/// with several categories only appears in its first one, so it isn't
+/// duplicated across sections.
+pub fn group_into_sections(apps: &[AppUiModel]) -> Vec<AppSection> {
+    let mut sections: Vec<AppSection> = Vec::new();
+    let mut uncategorized = Vec::new();
+
+    for app in apps {
+        match app.categories.first() {
+            Some(category) => match sections.iter_mut().find(|section| &section.name == category) {
+                Some(section) => section.apps.push(app.clone()),
+                None => sections.push(AppSection {
+                    name: category.clone(),
+                    apps: vec![app.clone()],
+                }),
+            },
+            None => uncategorized.push(app.clone()),
+        }
+    }
+
+    sections.sort_by(|a, b| a.name.cmp(&b.name));
+    if !uncategorized.is_empty() {
+        sections.push(AppSection {
+            name: UNCATEGORIZED_SECTION.to_string(),
+            apps: uncategorized,
+        });
+    }
+    sections
+}
+
+/// Splits `apps` into the pinned row (in `favorites`' configured order,
+/// de-duplicated) and the remaining grid, so a favorited app is never shown
+/// twice.
+pub fn partition_favorites(apps: &[AppUiModel], favorites: &[String]) -> (Vec<AppUiModel>, Vec<AppUiModel>) {
+    let pinned: Vec<AppUiModel> = favorites
+        .iter()
+        .filter_map(|app_id| apps.iter().find(|app| &app.app_id == app_id).cloned())
+        .collect();
+
+    let rest = apps
+        .iter()
+        .filter(|app| !favorites.contains(&app.app_id))
+        .cloned()
+        .collect();
+
+    (pinned, rest)
+}
+
+/// Builds the "Recent" row: `recent_ids` in order, skipping any app_id that
+/// no longer has a matching desktop entry.
+pub fn build_recent_row(apps: &[AppUiModel], recent_ids: &[String]) -> Vec<AppUiModel> {
+    recent_ids
+        .iter()
+        .filter_map(|app_id| apps.iter().find(|app| &app.app_id == app_id).cloned())
+        .collect()
+}
+
+/// Reorders `apps` per [`crate::settings::SortMode`]. `recent_ids` is
+/// most-recent-first (as stored in [`crate::settings::HomescreenSettings::recent_apps`]);
+/// apps that have never launched sort after anything with a recorded use,
+/// stably preserving their incoming order.
+pub fn sort_apps(
+    mut apps: Vec<AppUiModel>,
+    mode: crate::settings::SortMode,
+    recent_ids: &[String],
+    launch_counts: &std::collections::HashMap<String, u32>,
+) -> Vec<AppUiModel> {
+    use crate::settings::SortMode;
+
+    match mode {
+        SortMode::Alphabetical => {
+            apps.sort_by_key(|app| app.name.to_lowercase());
+        }
+        SortMode::MostUsed => {
+            apps.sort_by(|a, b| {
+                let a_count = launch_counts.get(&a.app_id).copied().unwrap_or(0);
+                let b_count = launch_counts.get(&b.app_id).copied().unwrap_or(0);
+                b_count.cmp(&a_count)
+            });
+        }
+        SortMode::RecentlyUsed => {
+            apps.sort_by_key(|app| {
+                recent_ids.iter().position(|id| id == &app.app_id).unwrap_or(usize::MAX)
+            });
+        }
+    }
+    apps
+}
+
+fn truncate_with_ellipsis(name: &str, max_len: usize) -> String {
+    if name.chars().count() <= max_len {
+        return name.to_string();
+    }
+
+    let truncated: String = name.chars().take(max_len).collect();
+    format!("{truncated}…")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_name_within_limit_is_unchanged() {
+        assert_eq!(truncate_with_ellipsis("Calculator", 15), "Calculator");
+    }
+
+    #[test]
+    fn ascii_name_over_limit_is_truncated_with_ellipsis() {
+        assert_eq!(
+            truncate_with_ellipsis("Really Long App Name", 15),
+            "Really Long App…"
+        );
+    }
+
+    #[test]
+    fn multibyte_name_over_limit_does_not_panic() {
+        let name = "日本語アプリケーションの名前です";
+        let truncated = truncate_with_ellipsis(name, 15);
+        assert!(truncated.ends_with('…'));
+        assert_eq!(truncated.chars().count(), 16);
+    }
+
+    #[test]
+    fn missing_icon_falls_back_to_default() {
+        assert_eq!(resolve_icon(None), desktop_entry::DEFAULT_ICON);
+    }
+
+    #[test]
+    fn theme_icon_name_is_kept_as_is() {
+        assert_eq!(resolve_icon(Some("firefox")), "firefox");
+    }
+
+    #[test]
+    fn missing_absolute_icon_path_falls_back_to_default() {
+        assert_eq!(resolve_icon(Some("/does/not/exist.svg")), desktop_entry::DEFAULT_ICON);
+    }
+
+    #[test]
+    fn generate_apps_ui_never_leaves_a_tile_without_an_icon() {
+        let apps = vec![AppEntry {
+            app_id: "mystery".to_string(),
+            name: "Mystery".to_string(),
+            icon_path: None,
+            categories: Vec::new(),
+            exec: String::new(),
+        }];
+        let tiles = generate_apps_ui(&apps);
+        assert_eq!(tiles[0].icon_path, desktop_entry::DEFAULT_ICON);
+    }
+
+    fn app(app_id: &str) -> AppUiModel {
+        app_with_categories(app_id, &[])
+    }
+
+    fn app_with_categories(app_id: &str, categories: &[&str]) -> AppUiModel {
+        AppUiModel {
+            app_id: app_id.to_string(),
+            name: app_id.to_string(),
+            icon_path: String::new(),
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            exec: String::new(),
+        }
+    }
+
+    #[test]
+    fn pinned_row_follows_favorites_order_and_excludes_from_rest() {
+        let apps = vec![app("a"), app("b"), app("c")];
+        let favorites = vec!["c".to_string(), "a".to_string()];
+
+        let (pinned, rest) = partition_favorites(&apps, &favorites);
+
+        assert_eq!(pinned, vec![app("c"), app("a")]);
+        assert_eq!(rest, vec![app("b")]);
+    }
+
+    #[test]
+    fn favorite_with_no_matching_app_is_skipped() {
+        let apps = vec![app("a")];
+        let favorites = vec!["missing".to_string(), "a".to_string()];
+
+        let (pinned, rest) = partition_favorites(&apps, &favorites);
+
+        assert_eq!(pinned, vec![app("a")]);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn sections_are_alphabetical_with_uncategorized_last() {
+        let apps = vec![
+            app_with_categories("browser", &["Internet"]),
+            app("notes"),
+            app_with_categories("chess", &["Games"]),
+        ];
+
+        let sections = group_into_sections(&apps);
+
+        let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Games", "Internet", "All"]);
+        assert_eq!(sections[2].apps, vec![app("notes")]);
+    }
+
+    #[test]
+    fn multi_category_app_only_appears_in_its_first_category() {
+        let apps = vec![app_with_categories("ide", &["Development", "Utility"])];
+
+        let sections = group_into_sections(&apps);
+
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].name, "Development");
+    }
+
+    #[test]
+    fn recent_row_follows_recent_order_and_skips_uninstalled_apps() {
+        let apps = vec![app("a"), app("b")];
+        let recent_ids = vec!["b".to_string(), "uninstalled".to_string(), "a".to_string()];
+
+        let recent = build_recent_row(&apps, &recent_ids);
+
+        assert_eq!(recent, vec![app("b"), app("a")]);
+    }
+
+    fn entry(app_id: &str) -> AppEntry {
+        AppEntry {
+            app_id: app_id.to_string(),
+            name: app_id.to_string(),
+            icon_path: None,
+            categories: Vec::new(),
+            exec: String::new(),
+        }
+    }
+
+    #[test]
+    fn exclude_drops_matching_app_ids() {
+        let filter = crate::settings::AppFilterSettings {
+            include_only: false,
+            include: Vec::new(),
+            exclude: vec!["b".to_string()],
+        };
+        let apps = filter_apps(vec![entry("a"), entry("b")], &filter);
+        assert_eq!(apps.iter().map(|a| a.app_id.as_str()).collect::<Vec<_>>(), vec!["a"]);
+    }
+
+    #[test]
+    fn include_only_keeps_only_the_listed_app_ids() {
+        let filter = crate::settings::AppFilterSettings {
+            include_only: true,
+            include: vec!["a".to_string()],
+            exclude: Vec::new(),
+        };
+        let apps = filter_apps(vec![entry("a"), entry("b")], &filter);
+        assert_eq!(apps.iter().map(|a| a.app_id.as_str()).collect::<Vec<_>>(), vec!["a"]);
+    }
+
+    #[test]
+    fn exclude_wins_over_include_when_both_list_the_same_app() {
+        let filter = crate::settings::AppFilterSettings {
+            include_only: true,
+            include: vec!["a".to_string()],
+            exclude: vec!["a".to_string()],
+        };
+        let apps = filter_apps(vec![entry("a")], &filter);
+        assert!(apps.is_empty());
+    }
+
+    fn app_named(app_id: &str, name: &str) -> AppUiModel {
+        AppUiModel {
+            app_id: app_id.to_string(),
+            name: name.to_string(),
+            icon_path: String::new(),
+            categories: Vec::new(),
+            exec: String::new(),
+        }
+    }
+
+    #[test]
+    fn alphabetical_sort_is_case_insensitive() {
+        let apps = vec![app_named("a", "banana"), app_named("b", "Apple"), app_named("c", "cherry")];
+        let sorted = sort_apps(apps, crate::settings::SortMode::Alphabetical, &[], &std::collections::HashMap::new());
+        let names: Vec<&str> = sorted.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn most_used_sort_orders_by_launch_count_descending() {
+        let apps = vec![app("a"), app("b"), app("c")];
+        let counts = std::collections::HashMap::from([("b".to_string(), 5), ("c".to_string(), 1)]);
+        let sorted = sort_apps(apps, crate::settings::SortMode::MostUsed, &[], &counts);
+        let ids: Vec<&str> = sorted.iter().map(|a| a.app_id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn recently_used_sort_follows_recent_ids_with_unused_apps_last() {
+        let apps = vec![app("a"), app("b"), app("c")];
+        let recent_ids = vec!["c".to_string(), "a".to_string()];
+        let sorted = sort_apps(apps, crate::settings::SortMode::RecentlyUsed, &recent_ids, &std::collections::HashMap::new());
+        let ids: Vec<&str> = sorted.iter().map(|a| a.app_id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "a", "b"]);
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use tokio::sync::{mpsc, oneshot, watch};
+
+/// Window state of a running app, as tracked by the launcher rather than
+/// the compositor - we don't have a toplevel handle to ask yet, so this is
+/// the source of truth the shell renders from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowState {
+    #[default]
+    Normal,
+    Minimized,
+    Maximized,
+}
+
+/// A single running application instance as tracked by the launcher. An
+/// `app_id` can have more than one `RunningApp` (e.g. two terminal
+/// windows), each with its own `instance_id` and title.
+#[derive(Debug, Clone)]
+pub struct RunningApp {
+    pub app_id: String,
+    pub instance_id: String,
+    pub title: String,
+    pub window_state: WindowState,
+}
+
+/// Messages accepted by [`AppManagerService`]'s event loop.
+#[derive(Debug)]
+pub enum AppManagerMessage {
+    /// Launch a fresh instance of `app_id`. Replies once it's tracked as
+    /// running, so a caller can tell "app manager unavailable" (the send
+    /// itself failed) apart from "the launch was rejected".
+    LaunchApp {
+        app_id: String,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    CloseApp {
+        app_id: String,
+    },
+    /// Close every running instance. Replies with how many were actually
+    /// closed, so the UI can report it if that's fewer than it expected.
+    CloseAllApps {
+        reply_to: oneshot::Sender<usize>,
+    },
+    /// Ask whether an app with the given id already has a running instance.
+    IsRunning {
+        app_id: String,
+        reply_to: oneshot::Sender<bool>,
+    },
+    /// Bring an already-running app's window to the foreground.
+    ActivateApp {
+        app_id: String,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    MinimizeApp {
+        app_id: String,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    MaximizeApp {
+        app_id: String,
+        reply_to: oneshot::Sender<Result<(), String>>,
+    },
+    /// The compositor reported a new title for one of our windows - e.g.
+    /// via the wlr-foreign-toplevel-management protocol.
+    UpdateTitle {
+        instance_id: String,
+        title: String,
+    },
+    /// Current snapshot of every running instance, for surfaces like the
+    /// app dock that render a live list.
+    ListRunning {
+        reply_to: oneshot::Sender<Vec<RunningApp>>,
+    },
+}
+
+/// Tracks currently running applications and answers queries about them.
+///
+/// The shell apps (homescreen, app-dock, ...) talk to a single
+/// `AppManagerService` instance over an `mpsc` channel so that window
+/// state stays consistent no matter which surface launched or closed an app.
+pub struct AppManagerService {
+    running: HashMap<String, RunningApp>,
+    next_instance_seq: u32,
+    receiver: mpsc::Receiver<AppManagerMessage>,
+    updates: watch::Sender<Vec<RunningApp>>,
+}
+
+impl AppManagerService {
+    pub fn new() -> (Self, mpsc::Sender<AppManagerMessage>) {
+        let (sender, receiver) = mpsc::channel(32);
+        let (updates, _) = watch::channel(Vec::new());
+        (
+            Self {
+                running: HashMap::new(),
+                next_instance_seq: 0,
+                receiver,
+                updates,
+            },
+            sender,
+        )
+    }
+
+    /// Subscribes to live snapshots of the running-app list, pushed on
+    /// every change (launch, close, title update, ...) instead of
+    /// requiring pollers to send `ListRunning` on a timer. Whatever feeds
+    /// the status bar's `FocusedAppModule` can use this to stay in sync.
+    pub fn subscribe(&self) -> watch::Receiver<Vec<RunningApp>> {
+        self.updates.subscribe()
+    }
+
+    pub async fn run(mut self) {
+        while let Some(message) = self.receiver.recv().await {
+            self.handle_message(message).await;
+        }
+    }
+
+    async fn handle_message(&mut self, message: AppManagerMessage) {
+        match message {
+            AppManagerMessage::LaunchApp { app_id, reply_to } => {
+                let instance_id = self.fresh_instance_id(&app_id);
+                self.running.insert(
+                    instance_id.clone(),
+                    RunningApp {
+                        app_id: app_id.clone(),
+                        instance_id,
+                        title: app_id,
+                        window_state: WindowState::default(),
+                    },
+                );
+                self.publish_update();
+                let _ = reply_to.send(Ok(()));
+            }
+            AppManagerMessage::CloseApp { app_id } => {
+                self.running.retain(|_, app| app.app_id != app_id);
+                self.publish_update();
+            }
+            AppManagerMessage::CloseAllApps { reply_to } => {
+                let closed = self.running.len();
+                self.running.clear();
+                let _ = reply_to.send(closed);
+                self.publish_update();
+            }
+            AppManagerMessage::IsRunning { app_id, reply_to } => {
+                let _ = reply_to.send(self.find_instance(&app_id).is_some());
+            }
+            AppManagerMessage::ActivateApp { app_id, reply_to } => {
+                let result = if self.find_instance(&app_id).is_some() {
+                    tracing::info!(%app_id, "activating running app window");
+                    Ok(())
+                } else {
+                    Err(format!("no running instance for app_id {app_id}"))
+                };
+                let _ = reply_to.send(result);
+            }
+            AppManagerMessage::MinimizeApp { app_id, reply_to } => {
+                let _ = reply_to.send(self.set_window_state(&app_id, WindowState::Minimized));
+                self.publish_update();
+            }
+            AppManagerMessage::MaximizeApp { app_id, reply_to } => {
+                let _ = reply_to.send(self.set_window_state(&app_id, WindowState::Maximized));
+                self.publish_update();
+            }
+            AppManagerMessage::UpdateTitle { instance_id, title } => {
+                if let Some(app) = self.running.get_mut(&instance_id) {
+                    app.title = title;
+                }
+                self.publish_update();
+            }
+            AppManagerMessage::ListRunning { reply_to } => {
+                let _ = reply_to.send(self.running.values().cloned().collect());
+            }
+        }
+    }
+
+    /// Pushes the current running-app snapshot to every `subscribe()`r.
+    /// `watch::Sender::send` only errors when there are no receivers left,
+    /// which is fine - nobody is listening yet.
+    fn publish_update(&self) {
+        let _ = self.updates.send(self.running.values().cloned().collect());
+    }
+
+    fn fresh_instance_id(&mut self, app_id: &str) -> String {
+        self.next_instance_seq += 1;
+        format!("{app_id}-{}", self.next_instance_seq)
+    }
+
+    fn find_instance(&self, app_id: &str) -> Option<&RunningApp> {
+        self.running.values().find(|app| app.app_id == app_id)
+    }
+
+    fn set_window_state(&mut self, app_id: &str, state: WindowState) -> Result<(), String> {
+        match self.running.values_mut().find(|app| app.app_id == app_id) {
+            Some(app) => {
+                app.window_state = state;
+                Ok(())
+            }
+            None => Err(format!("no running instance for app_id {app_id}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn subscribers_see_an_updated_snapshot_on_launch() {
+        let (service, sender) = AppManagerService::new();
+        let mut updates = service.subscribe();
+        tokio::spawn(service.run());
+
+        let (reply_to, _reply_rx) = oneshot::channel();
+        sender
+            .send(AppManagerMessage::LaunchApp { app_id: "terminal".to_string(), reply_to })
+            .await
+            .unwrap();
+
+        updates.changed().await.unwrap();
+        let snapshot = updates.borrow().clone();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].app_id, "terminal");
+    }
+}
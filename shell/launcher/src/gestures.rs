@@ -0,0 +1,107 @@
+use serde::Deserialize;
+
+/// Swipe-to-close geometry for the running-apps panel, configurable so a
+/// single screen resolution isn't hardcoded into the gesture handling.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct GestureSettings {
+    /// Height of the panel the swipe drags closed, in logical pixels.
+    #[serde(default = "default_panel_height")]
+    pub panel_height: f32,
+    /// Minimum downward drag distance before a release counts as "close".
+    #[serde(default = "default_drag_threshold")]
+    pub drag_threshold: f32,
+    /// How close to the top edge a drag must start to be picked up at all.
+    #[serde(default = "default_edge_activation_zone")]
+    pub edge_activation_zone: f32,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        Self {
+            panel_height: default_panel_height(),
+            drag_threshold: default_drag_threshold(),
+            edge_activation_zone: default_edge_activation_zone(),
+        }
+    }
+}
+
+fn default_panel_height() -> f32 {
+    480. - 124.
+}
+
+fn default_drag_threshold() -> f32 {
+    10.
+}
+
+fn default_edge_activation_zone() -> f32 {
+    124.
+}
+
+/// Tracks a swipe-to-close drag on the running-apps panel using the
+/// configured [`GestureSettings`] instead of one device's hardcoded
+/// geometry.
+#[derive(Debug)]
+pub struct Closer {
+    settings: GestureSettings,
+    drag_start_y: Option<f32>,
+}
+
+impl Closer {
+    pub fn new(settings: GestureSettings) -> Self {
+        Self { settings, drag_start_y: None }
+    }
+
+    /// Begins tracking a drag, but only if it started within the edge
+    /// activation zone - drags starting lower on the panel are ignored.
+    pub fn start_drag(&mut self, y: f32) {
+        if y <= self.settings.edge_activation_zone {
+            self.drag_start_y = Some(y);
+        }
+    }
+
+    /// Updates the drag and returns the panel's current offset, clamped to
+    /// `panel_height`. Returns `None` if no drag is in progress.
+    pub fn drag_to(&mut self, y: f32) -> Option<f32> {
+        let start = self.drag_start_y?;
+        Some((y - start).clamp(0., self.settings.panel_height))
+    }
+
+    /// Whether a drag ending at `offset` should close the panel.
+    pub fn should_close(&self, offset: f32) -> bool {
+        offset >= self.settings.drag_threshold
+    }
+
+    pub fn end_drag(&mut self) {
+        self.drag_start_y = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> GestureSettings {
+        GestureSettings { panel_height: 200., drag_threshold: 10., edge_activation_zone: 50. }
+    }
+
+    #[test]
+    fn drag_starting_outside_edge_zone_is_ignored() {
+        let mut closer = Closer::new(settings());
+        closer.start_drag(100.);
+        assert_eq!(closer.drag_to(150.), None);
+    }
+
+    #[test]
+    fn drag_offset_is_clamped_to_panel_height() {
+        let mut closer = Closer::new(settings());
+        closer.start_drag(10.);
+        assert_eq!(closer.drag_to(1000.), Some(200.));
+    }
+
+    #[test]
+    fn should_close_compares_against_configured_threshold() {
+        let closer = Closer::new(settings());
+        assert!(!closer.should_close(5.));
+        assert!(closer.should_close(10.));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod app_manager;
+pub mod gestures;
+pub mod long_press;
+pub mod settings;
+
+pub use app_manager::{AppManagerMessage, AppManagerService, RunningApp, WindowState};
+pub use gestures::{Closer, GestureSettings};
+pub use long_press::{LockModule, LongPressTimer};
+pub use settings::{find_config_path, read_settings_yml, LauncherSettings, SettingsError, SettingsWatcher};
@@ -0,0 +1,91 @@
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+/// Settings for the power/lock icon's long-press gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct LockModule {
+    /// Seconds the power/lock icon must be held before it triggers.
+    #[serde(default = "default_min_time_long_press")]
+    pub min_time_long_press: u8,
+}
+
+impl Default for LockModule {
+    fn default() -> Self {
+        Self { min_time_long_press: default_min_time_long_press() }
+    }
+}
+
+fn default_min_time_long_press() -> u8 {
+    3
+}
+
+/// Tracks a hold-to-trigger gesture on the power/lock icon, using
+/// [`LockModule::min_time_long_press`] for the required hold duration so
+/// the UI can render a progress ring and cancel cleanly on early release.
+#[derive(Debug)]
+pub struct LongPressTimer {
+    required: Duration,
+    pressed_at: Option<Instant>,
+}
+
+impl LongPressTimer {
+    pub fn new(module: LockModule) -> Self {
+        Self { required: Duration::from_secs(module.min_time_long_press as u64), pressed_at: None }
+    }
+
+    pub fn press(&mut self, now: Instant) {
+        self.pressed_at = Some(now);
+    }
+
+    /// Cancels the hold, e.g. because the user released early.
+    pub fn release(&mut self) {
+        self.pressed_at = None;
+    }
+
+    /// Progress towards triggering, from `0.0` to `1.0`, for rendering the
+    /// progress ring. Returns `None` if no hold is in progress.
+    pub fn progress(&self, now: Instant) -> Option<f32> {
+        let pressed_at = self.pressed_at?;
+        if self.required.is_zero() {
+            return Some(1.0);
+        }
+        let elapsed = now.saturating_duration_since(pressed_at).as_secs_f32();
+        Some((elapsed / self.required.as_secs_f32()).min(1.0))
+    }
+
+    /// Whether the hold has lasted long enough to trigger the power/lock
+    /// action.
+    pub fn is_triggered(&self, now: Instant) -> bool {
+        self.progress(now).is_some_and(|progress| progress >= 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_is_none_before_press() {
+        let timer = LongPressTimer::new(LockModule { min_time_long_press: 3 });
+        assert_eq!(timer.progress(Instant::now()), None);
+    }
+
+    #[test]
+    fn triggers_once_required_duration_elapses() {
+        let mut timer = LongPressTimer::new(LockModule { min_time_long_press: 3 });
+        let start = Instant::now();
+        timer.press(start);
+        assert!(!timer.is_triggered(start + Duration::from_secs(2)));
+        assert!(timer.is_triggered(start + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn release_cancels_the_hold() {
+        let mut timer = LongPressTimer::new(LockModule { min_time_long_press: 3 });
+        let start = Instant::now();
+        timer.press(start);
+        timer.release();
+        assert_eq!(timer.progress(start + Duration::from_secs(5)), None);
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc as std_mpsc;
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+use crate::gestures::GestureSettings;
+use crate::long_press::LockModule;
+
+/// Settings loaded from `settings.yml`, re-read whenever the file changes
+/// (see [`SettingsWatcher`]) so the launcher doesn't need a restart to
+/// pick up edits.
+#[derive(Debug, Clone, Deserialize, Default, PartialEq)]
+pub struct LauncherSettings {
+    #[serde(default)]
+    pub idle_timeout_secs: Option<u64>,
+    /// Swipe-to-close geometry for the running-apps panel.
+    #[serde(default)]
+    pub gestures: GestureSettings,
+    /// Power/lock icon long-press timing.
+    #[serde(default)]
+    pub lock: LockModule,
+}
+
+impl LauncherSettings {
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout_secs.map(Duration::from_secs)
+    }
+}
+
+/// Where `settings.yml` lives; see [`config_path::find_config_path`].
+pub fn find_config_path() -> Option<PathBuf> {
+    config_path::find_config_path("settings.yml")
+}
+
+/// Error from [`read_settings_yml`]. `Parse` carries the 1-based
+/// line/column `serde_yaml` reports, so a bad edit to `settings.yml` can
+/// be pointed at directly instead of just logging "invalid settings".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsError {
+    Io(String),
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::Io(message) => write!(f, "failed to read settings.yml: {message}"),
+            SettingsError::Parse { line, column, message } => {
+                write!(f, "settings.yml:{line}:{column}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+pub fn read_settings_yml(path: &Path) -> Result<LauncherSettings, SettingsError> {
+    let contents = std::fs::read_to_string(path).map_err(|err| SettingsError::Io(err.to_string()))?;
+    serde_yaml::from_str(&contents).map_err(|err| match err.location() {
+        Some(location) => SettingsError::Parse {
+            line: location.line(),
+            column: location.column(),
+            message: err.to_string(),
+        },
+        None => SettingsError::Parse {
+            line: 0,
+            column: 0,
+            message: err.to_string(),
+        },
+    })
+}
+
+/// Watches `settings.yml` for changes and re-reads it on every
+/// modification, sending the new settings down the returned channel. Drop
+/// the returned [`SettingsWatcher`] to stop watching.
+pub struct SettingsWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl SettingsWatcher {
+    pub fn watch(path: PathBuf) -> notify::Result<(Self, std_mpsc::Receiver<LauncherSettings>)> {
+        let (tx, rx) = std_mpsc::channel();
+        let watch_path = path.clone();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else { return };
+            if !event.kind.is_modify() {
+                return;
+            }
+            match read_settings_yml(&path) {
+                Ok(settings) => {
+                    let _ = tx.send(settings);
+                }
+                Err(err) => {
+                    tracing::warn!(%err, path = %path.display(), "failed to reload settings.yml");
+                }
+            }
+        })?;
+        watcher.watch(&watch_path, RecursiveMode::NonRecursive)?;
+        Ok((Self { _watcher: watcher }, rx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_includes_line_and_column() {
+        let err = serde_yaml::from_str::<LauncherSettings>("idle_timeout_secs: [not, a, number]")
+            .unwrap_err();
+        let Some(location) = err.location() else {
+            panic!("expected serde_yaml to report an error location");
+        };
+        assert!(location.line() >= 1);
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+/// How long the shake animation plays after a wrong PIN.
+const SHAKE_DURATION: Duration = Duration::from_millis(400);
+
+/// Drives the "wrong PIN" shake animation shown on the lock screen's PIN
+/// pad. Separate from [`crate::pin::PinEntry`] so the view layer can poll
+/// `is_shaking` on every frame without mutating entry state.
+#[derive(Debug, Clone, Default)]
+pub struct WrongPinFeedback {
+    shake_started_at: Option<Instant>,
+}
+
+impl WrongPinFeedback {
+    pub fn trigger(&mut self) {
+        self.shake_started_at = Some(Instant::now());
+    }
+
+    pub fn is_shaking(&self) -> bool {
+        self.shake_started_at
+            .map(|started| started.elapsed() < SHAKE_DURATION)
+            .unwrap_or(false)
+    }
+
+    /// 0.0-1.0 progress through the shake animation, for easing the visual
+    /// offset. `0.0` once the animation has finished or never started.
+    pub fn progress(&self) -> f32 {
+        match self.shake_started_at {
+            Some(started) if self.is_shaking() => {
+                started.elapsed().as_secs_f32() / SHAKE_DURATION.as_secs_f32()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_shaking_before_trigger() {
+        assert!(!WrongPinFeedback::default().is_shaking());
+    }
+
+    #[test]
+    fn shaking_immediately_after_trigger() {
+        let mut feedback = WrongPinFeedback::default();
+        feedback.trigger();
+        assert!(feedback.is_shaking());
+    }
+}
@@ -0,0 +1,13 @@
+pub mod feedback;
+pub mod modifiers;
+pub mod notifications;
+pub mod pattern;
+pub mod pin;
+pub mod settings;
+
+pub use feedback::WrongPinFeedback;
+pub use modifiers::KeyboardModifiers;
+pub use notifications::{LockNotifications, LockNotificationsSettings, LockScreenNotification};
+pub use pattern::{hash_sequence, PatternEntry, PatternSettings};
+pub use pin::{PinEntry, PinSettings};
+pub use settings::UnlockMethod;
@@ -0,0 +1,52 @@
+/// Keyboard modifier state pushed in from the layer-shell keyboard, so the
+/// greeter and lock screen can show a small indicator near the
+/// password/PIN field - a rejected login is often no more than caps lock
+/// toggled on. Updated live as the compositor reports modifier changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyboardModifiers {
+    caps_lock: bool,
+    num_lock: bool,
+}
+
+impl KeyboardModifiers {
+    pub fn set_caps_lock(&mut self, enabled: bool) {
+        self.caps_lock = enabled;
+    }
+
+    pub fn set_num_lock(&mut self, enabled: bool) {
+        self.num_lock = enabled;
+    }
+
+    pub fn caps_lock(&self) -> bool {
+        self.caps_lock
+    }
+
+    pub fn num_lock(&self) -> bool {
+        self.num_lock
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_off_by_default() {
+        let modifiers = KeyboardModifiers::default();
+        assert!(!modifiers.caps_lock());
+        assert!(!modifiers.num_lock());
+    }
+
+    #[test]
+    fn tracks_each_modifier_independently() {
+        let mut modifiers = KeyboardModifiers::default();
+        modifiers.set_caps_lock(true);
+        assert!(modifiers.caps_lock());
+        assert!(!modifiers.num_lock());
+
+        modifiers.set_caps_lock(false);
+        modifiers.set_num_lock(true);
+        assert!(!modifiers.caps_lock());
+        assert!(modifiers.num_lock());
+    }
+}
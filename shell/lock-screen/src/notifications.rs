@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+/// How many recent notifications are kept for the lock screen - older ones
+/// fall off as fresh ones arrive.
+const MAX_VISIBLE: usize = 5;
+
+/// A notification pushed to the lock screen, e.g. by the notification
+/// daemon. Kept independent of `notification::Notification` so lock-screen
+/// stays a pure-logic crate that doesn't depend on the notification crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockScreenNotification {
+    pub id: u32,
+    pub app_name: String,
+    pub summary: String,
+    pub body: String,
+    pub icon: String,
+}
+
+/// Privacy settings for lock-screen notifications, loaded from
+/// `settings.yml`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct LockNotificationsSettings {
+    /// When true, notification content is hidden and only a count of new
+    /// notifications is shown.
+    #[serde(default)]
+    pub hide_content: bool,
+}
+
+/// Read-only (from the lock screen's perspective) list of recent
+/// notifications rendered above the PIN pad. Tapping an entry is only
+/// meaningful after unlock, at which point the caller can look it up by
+/// id via [`LockNotifications::notification`] and route to its app.
+#[derive(Debug, Clone, Default)]
+pub struct LockNotifications {
+    notifications: VecDeque<LockScreenNotification>,
+    settings: LockNotificationsSettings,
+}
+
+impl LockNotifications {
+    pub fn new(settings: LockNotificationsSettings) -> Self {
+        Self {
+            notifications: VecDeque::new(),
+            settings,
+        }
+    }
+
+    pub fn push(&mut self, notification: LockScreenNotification) {
+        self.notifications.push_front(notification);
+        if self.notifications.len() > MAX_VISIBLE {
+            self.notifications.pop_back();
+        }
+    }
+
+    pub fn remove(&mut self, id: u32) {
+        self.notifications.retain(|n| n.id != id);
+    }
+
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+    }
+
+    pub fn count(&self) -> usize {
+        self.notifications.len()
+    }
+
+    pub fn notification(&self, id: u32) -> Option<&LockScreenNotification> {
+        self.notifications.iter().find(|n| n.id == id)
+    }
+
+    /// Notifications to render, newest first, or empty when
+    /// [`LockNotificationsSettings::hide_content`] is set - the caller
+    /// should fall back to a "N new notifications" count in that case.
+    pub fn visible(&self) -> Vec<&LockScreenNotification> {
+        if self.settings.hide_content {
+            Vec::new()
+        } else {
+            self.notifications.iter().collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(id: u32) -> LockScreenNotification {
+        LockScreenNotification {
+            id,
+            app_name: "Messages".to_string(),
+            summary: "New message".to_string(),
+            body: "Hey there".to_string(),
+            icon: "messages".to_string(),
+        }
+    }
+
+    #[test]
+    fn pushed_notifications_show_newest_first() {
+        let mut notifications = LockNotifications::default();
+        notifications.push(notification(1));
+        notifications.push(notification(2));
+        assert_eq!(notifications.visible(), vec![&notification(2), &notification(1)]);
+    }
+
+    #[test]
+    fn oldest_falls_off_past_max_visible() {
+        let mut notifications = LockNotifications::default();
+        for id in 0..10 {
+            notifications.push(notification(id));
+        }
+        assert_eq!(notifications.count(), MAX_VISIBLE);
+        assert!(notifications.notification(0).is_none());
+        assert!(notifications.notification(9).is_some());
+    }
+
+    #[test]
+    fn hidden_content_setting_reports_no_visible_items() {
+        let mut notifications = LockNotifications::new(LockNotificationsSettings { hide_content: true });
+        notifications.push(notification(1));
+        assert!(notifications.visible().is_empty());
+        assert_eq!(notifications.count(), 1);
+    }
+
+    #[test]
+    fn remove_drops_a_closed_notification() {
+        let mut notifications = LockNotifications::default();
+        notifications.push(notification(1));
+        notifications.remove(1);
+        assert!(notifications.notification(1).is_none());
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+
+/// Pattern-unlock settings, loaded from `settings.yml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternSettings {
+    /// Side length of the dot grid, e.g. `3` for the standard 3x3 layout.
+    #[serde(default = "default_grid_size")]
+    pub grid_size: u8,
+    /// [`hash_sequence`] of the stored pattern's dot sequence. `None`
+    /// means no pattern has been set yet.
+    #[serde(default)]
+    pub stored_hash: Option<u64>,
+}
+
+impl Default for PatternSettings {
+    fn default() -> Self {
+        Self {
+            grid_size: default_grid_size(),
+            stored_hash: None,
+        }
+    }
+}
+
+fn default_grid_size() -> u8 {
+    3
+}
+
+/// Hashes a dot sequence for storage/comparison. Not cryptographic - a
+/// pattern is compared against a hash kept in local settings, the same
+/// trust boundary a plaintext PIN is compared across elsewhere in this
+/// codebase, so a fast, dependency-free [`DefaultHasher`] is enough.
+pub fn hash_sequence(dots: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    dots.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// State of the pattern-unlock dot grid on the lock screen: a drag
+/// connects dots (numbered left-to-right, top-to-bottom) into a sequence.
+#[derive(Debug, Clone, Default)]
+pub struct PatternEntry {
+    dots: Vec<u8>,
+    grid_size: u8,
+}
+
+impl PatternEntry {
+    pub fn new(settings: &PatternSettings) -> Self {
+        Self {
+            dots: Vec::new(),
+            grid_size: settings.grid_size,
+        }
+    }
+
+    /// Connects `dot` into the in-progress sequence. Ignored if `dot` is
+    /// outside the grid or already part of the sequence - a pattern drag
+    /// can't cross itself.
+    pub fn connect_dot(&mut self, dot: u8) {
+        let dot_count = self.grid_size as u32 * self.grid_size as u32;
+        if (dot as u32) < dot_count && !self.dots.contains(&dot) {
+            self.dots.push(dot);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.dots.clear();
+    }
+
+    pub fn dots(&self) -> &[u8] {
+        &self.dots
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    /// Whether the in-progress sequence matches `stored_hash`. Always
+    /// `false` for an empty sequence so a bare drag-release can't match an
+    /// unset pattern.
+    pub fn matches(&self, stored_hash: Option<u64>) -> bool {
+        !self.dots.is_empty() && stored_hash == Some(hash_sequence(&self.dots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_a_dot_already_in_the_sequence() {
+        let mut entry = PatternEntry::new(&PatternSettings::default());
+        entry.connect_dot(0);
+        entry.connect_dot(4);
+        entry.connect_dot(0);
+        assert_eq!(entry.dots(), &[0, 4]);
+    }
+
+    #[test]
+    fn ignores_a_dot_outside_the_grid() {
+        let mut entry = PatternEntry::new(&PatternSettings { grid_size: 3, stored_hash: None });
+        entry.connect_dot(20);
+        assert!(entry.is_empty());
+    }
+
+    #[test]
+    fn matches_the_same_sequence_hash() {
+        let settings = PatternSettings {
+            grid_size: 3,
+            stored_hash: Some(hash_sequence(&[0, 1, 2, 5, 8])),
+        };
+        let mut entry = PatternEntry::new(&settings);
+        for dot in [0, 1, 2, 5, 8] {
+            entry.connect_dot(dot);
+        }
+        assert!(entry.matches(settings.stored_hash));
+    }
+
+    #[test]
+    fn does_not_match_a_different_sequence() {
+        let settings = PatternSettings {
+            grid_size: 3,
+            stored_hash: Some(hash_sequence(&[0, 1, 2])),
+        };
+        let mut entry = PatternEntry::new(&settings);
+        entry.connect_dot(3);
+        entry.connect_dot(4);
+        assert!(!entry.matches(settings.stored_hash));
+    }
+
+    #[test]
+    fn empty_sequence_never_matches() {
+        let entry = PatternEntry::new(&PatternSettings::default());
+        assert!(!entry.matches(Some(hash_sequence(&[]))));
+    }
+}
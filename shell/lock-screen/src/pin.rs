@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// PIN entry settings, loaded from `settings.yml`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PinSettings {
+    #[serde(default = "default_pin_length")]
+    pub length: u8,
+}
+
+impl Default for PinSettings {
+    fn default() -> Self {
+        Self {
+            length: default_pin_length(),
+        }
+    }
+}
+
+fn default_pin_length() -> u8 {
+    4
+}
+
+/// State of the PIN entry pad on the lock screen.
+#[derive(Debug, Clone, Default)]
+pub struct PinEntry {
+    digits: String,
+    length: u8,
+}
+
+impl PinEntry {
+    pub fn new(settings: PinSettings) -> Self {
+        Self {
+            digits: String::new(),
+            length: settings.length,
+        }
+    }
+
+    /// Append a digit, ignored once the configured length is reached.
+    pub fn push_digit(&mut self, digit: char) {
+        if self.digits.len() < self.length as usize && digit.is_ascii_digit() {
+            self.digits.push(digit);
+        }
+    }
+
+    pub fn pop_digit(&mut self) {
+        self.digits.pop();
+    }
+
+    pub fn clear(&mut self) {
+        self.digits.clear();
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.digits.len() == self.length as usize
+    }
+
+    pub fn value(&self) -> &str {
+        &self.digits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_accepting_digits_past_configured_length() {
+        let mut entry = PinEntry::new(PinSettings { length: 4 });
+        for digit in "123456".chars() {
+            entry.push_digit(digit);
+        }
+        assert_eq!(entry.value(), "1234");
+        assert!(entry.is_complete());
+    }
+
+    #[test]
+    fn respects_a_six_digit_configuration() {
+        let mut entry = PinEntry::new(PinSettings { length: 6 });
+        for digit in "1234".chars() {
+            entry.push_digit(digit);
+        }
+        assert!(!entry.is_complete());
+        entry.push_digit('5');
+        entry.push_digit('6');
+        assert!(entry.is_complete());
+    }
+}
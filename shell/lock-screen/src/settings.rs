@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Which unlock method the lock screen presents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnlockMethod {
+    #[default]
+    Pin,
+    Pattern,
+}
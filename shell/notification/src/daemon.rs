@@ -0,0 +1,189 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, Mutex};
+use zbus::dbus_interface;
+
+use crate::notification::{Hints, Notification};
+
+/// How many past notifications [`NotificationDaemon::history`] keeps
+/// around before dropping the oldest.
+const HISTORY_CAPACITY: usize = 100;
+
+/// Emitted to the shell whenever a notification arrives, so a status bar /
+/// banner surface can render it without polling the daemon.
+#[derive(Debug, Clone)]
+pub enum NotificationEvent {
+    Arrived(Notification),
+    Closed(u32),
+}
+
+/// Implements the freedesktop.org Desktop Notifications Specification's
+/// `org.freedesktop.Notifications` interface. Registered on the session
+/// bus under the well-known name `org.freedesktop.Notifications` so any
+/// application's `notify-send`/libnotify calls land here.
+pub struct NotificationDaemon {
+    next_id: AtomicU32,
+    active: Arc<Mutex<HashMap<u32, Notification>>>,
+    history: Mutex<VecDeque<Notification>>,
+    events: mpsc::Sender<NotificationEvent>,
+    do_not_disturb: AtomicBool,
+}
+
+impl NotificationDaemon {
+    pub fn new() -> (Arc<Self>, mpsc::Receiver<NotificationEvent>) {
+        let (tx, rx) = mpsc::channel(64);
+        (
+            Arc::new(Self {
+                next_id: AtomicU32::new(1),
+                active: Arc::new(Mutex::new(HashMap::new())),
+                history: Mutex::new(VecDeque::with_capacity(HISTORY_CAPACITY)),
+                events: tx,
+                do_not_disturb: AtomicBool::new(false),
+            }),
+            rx,
+        )
+    }
+
+    /// Most recent notifications first, newest-to-oldest, capped at
+    /// [`HISTORY_CAPACITY`].
+    pub async fn history(&self) -> Vec<Notification> {
+        self.history.lock().await.iter().cloned().collect()
+    }
+
+    async fn push_history(&self, notification: Notification) {
+        let mut history = self.history.lock().await;
+        history.push_front(notification);
+        if history.len() > HISTORY_CAPACITY {
+            history.pop_back();
+        }
+    }
+
+    /// Toggle Do-Not-Disturb. While enabled, notifications still get an id
+    /// and land in history, but [`NotificationEvent::Arrived`] is not
+    /// emitted, so the shell renders no banner for them.
+    pub fn set_do_not_disturb(&self, enabled: bool) {
+        self.do_not_disturb.store(enabled, Ordering::SeqCst);
+    }
+
+    pub fn is_do_not_disturb(&self) -> bool {
+        self.do_not_disturb.load(Ordering::SeqCst)
+    }
+
+    /// Remove `id` from the active set after `timeout`, unless it was
+    /// already closed (by the caller or `CloseNotification`) in the
+    /// meantime.
+    fn schedule_auto_dismiss(&self, id: u32, timeout: std::time::Duration) {
+        let active = self.active.clone();
+        let events = self.events.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(timeout).await;
+            if active.lock().await.remove(&id).is_some() {
+                let _ = events.send(NotificationEvent::Closed(id)).await;
+            }
+        });
+    }
+}
+
+#[dbus_interface(name = "org.freedesktop.Notifications")]
+impl NotificationDaemon {
+    #[allow(clippy::too_many_arguments)]
+    async fn notify(
+        &self,
+        app_name: String,
+        replaces_id: u32,
+        app_icon: String,
+        summary: String,
+        body: String,
+        actions: Vec<String>,
+        _hints: Hints,
+        _expire_timeout: i32,
+    ) -> u32 {
+        let id = if replaces_id != 0 {
+            replaces_id
+        } else {
+            self.next_id.fetch_add(1, Ordering::SeqCst)
+        };
+
+        let timeout = Notification::resolve_timeout(_expire_timeout);
+        let notification = Notification {
+            id,
+            app_name,
+            summary,
+            body,
+            icon: app_icon,
+            actions: Notification::action_keys(&actions),
+            timeout,
+        };
+
+        self.active.lock().await.insert(id, notification.clone());
+        self.push_history(notification.clone()).await;
+        if !self.is_do_not_disturb() {
+            let _ = self.events.send(NotificationEvent::Arrived(notification)).await;
+        }
+
+        if let Some(timeout) = timeout {
+            self.schedule_auto_dismiss(id, timeout);
+        }
+
+        id
+    }
+
+    /// Invoked when the shell renders a notification's action buttons and
+    /// the user clicks one, or when the notification body itself is
+    /// clicked (action key `"default"`).
+    async fn invoke_action(
+        &self,
+        #[zbus(signal_context)] signal_ctxt: zbus::SignalContext<'_>,
+        id: u32,
+        action_key: String,
+    ) -> zbus::fdo::Result<()> {
+        let has_action = self
+            .active
+            .lock()
+            .await
+            .get(&id)
+            .map(|n| n.actions.iter().any(|(key, _)| key == &action_key))
+            .unwrap_or(false);
+        if has_action {
+            Self::action_invoked(&signal_ctxt, id, action_key)
+                .await
+                .map_err(|err| zbus::fdo::Error::Failed(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    #[dbus_interface(signal)]
+    async fn action_invoked(
+        signal_ctxt: &zbus::SignalContext<'_>,
+        id: u32,
+        action_key: String,
+    ) -> zbus::Result<()>;
+
+    async fn close_notification(&self, id: u32) {
+        if self.active.lock().await.remove(&id).is_some() {
+            let _ = self.events.send(NotificationEvent::Closed(id)).await;
+        }
+    }
+
+    fn get_capabilities(&self) -> Vec<String> {
+        vec!["body".to_string(), "actions".to_string()]
+    }
+
+    fn get_server_information(&self) -> (String, String, String, String) {
+        (
+            "mechanix-notification".to_string(),
+            "mecha".to_string(),
+            env!("CARGO_PKG_VERSION").to_string(),
+            "1.2".to_string(),
+        )
+    }
+
+    #[dbus_interface(signal)]
+    async fn notification_closed(
+        signal_ctxt: &zbus::SignalContext<'_>,
+        id: u32,
+        reason: u32,
+    ) -> zbus::Result<()>;
+}
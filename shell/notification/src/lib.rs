@@ -0,0 +1,5 @@
+pub mod daemon;
+pub mod notification;
+
+pub use daemon::{NotificationDaemon, NotificationEvent};
+pub use notification::Notification;
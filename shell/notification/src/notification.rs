@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Timeout applied when the caller passes `expire_timeout == -1` (the
+/// spec's "use server default").
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A single notification, as received through the
+/// `org.freedesktop.Notifications.Notify` method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub id: u32,
+    pub app_name: String,
+    pub summary: String,
+    pub body: String,
+    pub icon: String,
+    /// Flattened `(action_key, action_label)` pairs from the `actions` array.
+    pub actions: Vec<(String, String)>,
+    /// `None` means never auto-dismiss (the caller passed `expire_timeout == 0`).
+    pub timeout: Option<Duration>,
+}
+
+impl Notification {
+    pub fn action_keys(actions: &[String]) -> Vec<(String, String)> {
+        actions
+            .chunks(2)
+            .filter_map(|chunk| match chunk {
+                [key, label] => Some((key.clone(), label.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn has_default_action(&self) -> bool {
+        self.actions.iter().any(|(key, _)| key == "default")
+    }
+
+    /// Map the raw `expire_timeout` argument from `Notify` (-1 = default,
+    /// 0 = never expire, >0 = milliseconds) to a [`Duration`].
+    pub fn resolve_timeout(expire_timeout: i32) -> Option<Duration> {
+        match expire_timeout {
+            0 => None,
+            -1 => Some(DEFAULT_TIMEOUT),
+            millis => Some(Duration::from_millis(millis as u64)),
+        }
+    }
+}
+
+pub type Hints = HashMap<String, zbus::zvariant::OwnedValue>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_means_never_expire() {
+        assert_eq!(Notification::resolve_timeout(0), None);
+    }
+
+    #[test]
+    fn negative_one_means_server_default() {
+        assert_eq!(Notification::resolve_timeout(-1), Some(DEFAULT_TIMEOUT));
+    }
+
+    #[test]
+    fn positive_value_is_milliseconds() {
+        assert_eq!(
+            Notification::resolve_timeout(2500),
+            Some(Duration::from_millis(2500))
+        );
+    }
+}
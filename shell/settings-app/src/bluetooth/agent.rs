@@ -0,0 +1,90 @@
+use tokio::sync::{mpsc, oneshot};
+use zbus::dbus_interface;
+use zbus::zvariant::OwnedObjectPath;
+use zbus::Connection;
+
+/// The object path the pairing agent is registered under.
+pub const PAIRING_AGENT_PATH: &str = "/com/mecha/Settings/BluetoothAgent";
+
+/// A pairing prompt BlueZ needs the settings-app UI to answer before it
+/// continues pairing `device`. The UI is expected to show a dialog and
+/// send its answer back over `reply`.
+#[derive(Debug)]
+pub enum PairingRequest {
+    /// Ask the user to confirm `passkey` matches what the device shows.
+    Confirm {
+        device: OwnedObjectPath,
+        passkey: u32,
+        reply: oneshot::Sender<bool>,
+    },
+    /// Ask the user to type in the device's PIN.
+    Pin {
+        device: OwnedObjectPath,
+        reply: oneshot::Sender<Option<String>>,
+    },
+}
+
+/// Implements `org.bluez.Agent1` so BlueZ has somewhere to send pairing
+/// prompts during [`super::screen::BluetoothScreen::pair`]. Prompts are
+/// forwarded to the settings-app UI over an unbounded channel rather than
+/// auto-accepted, since blindly confirming pairing requests would defeat
+/// the point of asking.
+pub struct PairingAgent {
+    prompts: mpsc::UnboundedSender<PairingRequest>,
+}
+
+impl PairingAgent {
+    /// Returns the agent plus the receiving end of its prompt channel,
+    /// which the UI event loop should poll to show pairing dialogs.
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<PairingRequest>) {
+        let (prompts, requests) = mpsc::unbounded_channel();
+        (Self { prompts }, requests)
+    }
+}
+
+#[dbus_interface(name = "org.bluez.Agent1")]
+impl PairingAgent {
+    async fn request_confirmation(&self, device: OwnedObjectPath, passkey: u32) -> zbus::fdo::Result<()> {
+        let (reply, answer) = oneshot::channel();
+        self.prompts
+            .send(PairingRequest::Confirm { device, passkey, reply })
+            .map_err(|_| zbus::fdo::Error::Failed("pairing UI is not listening".to_string()))?;
+
+        match answer.await {
+            Ok(true) => Ok(()),
+            Ok(false) | Err(_) => Err(zbus::fdo::Error::AccessDenied(
+                "user rejected the pairing confirmation".to_string(),
+            )),
+        }
+    }
+
+    async fn request_pin_code(&self, device: OwnedObjectPath) -> zbus::fdo::Result<String> {
+        let (reply, answer) = oneshot::channel();
+        self.prompts
+            .send(PairingRequest::Pin { device, reply })
+            .map_err(|_| zbus::fdo::Error::Failed("pairing UI is not listening".to_string()))?;
+
+        answer
+            .await
+            .ok()
+            .flatten()
+            .ok_or_else(|| zbus::fdo::Error::AccessDenied("user cancelled PIN entry".to_string()))
+    }
+
+    fn cancel(&self) {
+        tracing::info!("BlueZ cancelled the in-flight pairing request");
+    }
+}
+
+/// Serves `agent` as `org.bluez.Agent1` at [`PAIRING_AGENT_PATH`] and
+/// registers it with BlueZ as the default agent, so pairing prompts for
+/// devices started via [`super::screen::BluetoothScreen::pair`] are routed
+/// to it.
+pub async fn register_pairing_agent(connection: &Connection, agent: PairingAgent) -> zbus::Result<()> {
+    connection.object_server().at(PAIRING_AGENT_PATH, agent).await?;
+
+    let path = OwnedObjectPath::try_from(PAIRING_AGENT_PATH)?;
+    let agent_manager = bluez::AgentManagerProxy::builder(connection).build().await?;
+    agent_manager.register_agent(&path, "KeyboardDisplay").await?;
+    agent_manager.request_default_agent(&path).await
+}
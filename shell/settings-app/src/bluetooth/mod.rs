@@ -0,0 +1,7 @@
+pub mod agent;
+pub mod model;
+pub mod screen;
+
+pub use agent::{register_pairing_agent, PairingAgent, PairingRequest, PAIRING_AGENT_PATH};
+pub use model::BluetoothModel;
+pub use screen::{BluetoothDevice, BluetoothScreen};
@@ -0,0 +1,78 @@
+use zbus::zvariant::OwnedObjectPath;
+use zbus::Connection;
+
+use super::screen::BluetoothDevice;
+
+/// Client for the Bluetooth adapter at `adapter_path`, giving the
+/// settings-app a reactive model layer for Bluetooth the way
+/// [`crate::networking::WirelessModel`] already does for saved Wi-Fi
+/// networks. Unlike `WirelessModel`, there's no `com.mecha.Bluetooth`
+/// service to front it yet, so this talks to BlueZ directly.
+pub struct BluetoothModel {
+    adapter_path: OwnedObjectPath,
+}
+
+impl BluetoothModel {
+    pub fn new(adapter_path: OwnedObjectPath) -> Self {
+        Self { adapter_path }
+    }
+
+    pub async fn is_enabled(&self, connection: &Connection) -> zbus::Result<bool> {
+        self.adapter(connection).await?.powered().await
+    }
+
+    pub async fn enable(&self, connection: &Connection) -> zbus::Result<()> {
+        self.adapter(connection).await?.set_powered(true).await
+    }
+
+    pub async fn disable(&self, connection: &Connection) -> zbus::Result<()> {
+        self.adapter(connection).await?.set_powered(false).await
+    }
+
+    /// Devices already paired with this adapter.
+    pub async fn known_devices(&self, connection: &Connection) -> zbus::Result<Vec<BluetoothDevice>> {
+        let devices = self.all_devices(connection).await?;
+        Ok(devices.into_iter().filter(|device| device.paired).collect())
+    }
+
+    /// Devices the most recent scan turned up that aren't paired yet.
+    pub async fn scanned_devices(&self, connection: &Connection) -> zbus::Result<Vec<BluetoothDevice>> {
+        let devices = self.all_devices(connection).await?;
+        Ok(devices.into_iter().filter(|device| !device.paired).collect())
+    }
+
+    /// Connects to a device by MAC address, e.g. `"AA:BB:CC:DD:EE:FF"`.
+    pub async fn connect(&self, connection: &Connection, mac: &str) -> zbus::Result<()> {
+        let path = bluez::device_path(&self.adapter_path, mac)?;
+        let device = bluez::DeviceProxy::builder(connection).path(path)?.build().await?;
+        device.connect().await
+    }
+
+    /// Unpairs and removes the device with this MAC address.
+    pub async fn forget(&self, connection: &Connection, mac: &str) -> zbus::Result<()> {
+        let path = bluez::device_path(&self.adapter_path, mac)?;
+        self.adapter(connection).await?.remove_device(&path).await
+    }
+
+    async fn adapter<'a>(&self, connection: &'a Connection) -> zbus::Result<bluez::AdapterProxy<'a>> {
+        bluez::AdapterProxy::builder(connection)
+            .path(self.adapter_path.clone())?
+            .build()
+            .await
+    }
+
+    async fn all_devices(&self, connection: &Connection) -> zbus::Result<Vec<BluetoothDevice>> {
+        let object_manager = bluez::ObjectManagerProxy::builder(connection).build().await?;
+        let paths = object_manager.device_paths_under(self.adapter_path.as_str()).await?;
+
+        let mut devices = Vec::with_capacity(paths.len());
+        for path in paths {
+            let device = bluez::DeviceProxy::builder(connection)
+                .path(path.clone())?
+                .build()
+                .await?;
+            devices.push(BluetoothDevice::from_info(path, device.info().await?));
+        }
+        Ok(devices)
+    }
+}
@@ -0,0 +1,89 @@
+use bluez::DeviceInfo;
+use zbus::zvariant::OwnedObjectPath;
+use zbus::Connection;
+
+/// A Bluetooth device as listed on the settings-app Bluetooth screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BluetoothDevice {
+    pub path: OwnedObjectPath,
+    pub address: String,
+    pub name: String,
+    pub paired: bool,
+    pub connected: bool,
+}
+
+impl BluetoothDevice {
+    pub(crate) fn from_info(path: OwnedObjectPath, info: DeviceInfo) -> Self {
+        Self {
+            path,
+            address: info.address,
+            name: info.name,
+            paired: info.paired,
+            connected: info.connected,
+        }
+    }
+}
+
+/// The Bluetooth screen in the settings app: scan, pair, connect, and
+/// forget devices on `adapter_path` (e.g. `/org/bluez/hci0`) over BlueZ's
+/// D-Bus API directly, the way [`super::super::networking::NetworkingScreen`]
+/// talks to NetworkManager.
+pub struct BluetoothScreen {
+    adapter_path: OwnedObjectPath,
+}
+
+impl BluetoothScreen {
+    pub fn new(adapter_path: OwnedObjectPath) -> Self {
+        Self { adapter_path }
+    }
+
+    /// Starts discovery and returns the devices BlueZ currently knows
+    /// about under this adapter - both newly discovered and already
+    /// paired ones, connected-first.
+    pub async fn scan(&self, connection: &Connection) -> zbus::Result<Vec<BluetoothDevice>> {
+        let adapter = bluez::AdapterProxy::builder(connection)
+            .path(self.adapter_path.clone())?
+            .build()
+            .await?;
+        adapter.start_discovery().await.ok();
+
+        let object_manager = bluez::ObjectManagerProxy::builder(connection).build().await?;
+        let device_paths = object_manager.device_paths_under(self.adapter_path.as_str()).await?;
+
+        let mut devices = Vec::with_capacity(device_paths.len());
+        for path in device_paths {
+            let device = bluez::DeviceProxy::builder(connection)
+                .path(path.clone())?
+                .build()
+                .await?;
+            devices.push(BluetoothDevice::from_info(path, device.info().await?));
+        }
+
+        devices.sort_by(|a, b| b.connected.cmp(&a.connected).then(a.name.cmp(&b.name)));
+        Ok(devices)
+    }
+
+    pub async fn pair(&self, connection: &Connection, device: &OwnedObjectPath) -> zbus::Result<()> {
+        let device = bluez::DeviceProxy::builder(connection).path(device.clone())?.build().await?;
+        device.pair().await
+    }
+
+    pub async fn connect(&self, connection: &Connection, device: &OwnedObjectPath) -> zbus::Result<()> {
+        let device = bluez::DeviceProxy::builder(connection).path(device.clone())?.build().await?;
+        device.connect().await
+    }
+
+    pub async fn disconnect(&self, connection: &Connection, device: &OwnedObjectPath) -> zbus::Result<()> {
+        let device = bluez::DeviceProxy::builder(connection).path(device.clone())?.build().await?;
+        device.disconnect().await
+    }
+
+    /// Unpairs and removes `device` from the adapter entirely.
+    pub async fn forget(&self, connection: &Connection, device: &OwnedObjectPath) -> zbus::Result<()> {
+        let adapter = bluez::AdapterProxy::builder(connection)
+            .path(self.adapter_path.clone())?
+            .build()
+            .await?;
+        adapter.remove_device(device).await
+    }
+}
@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use status_bar::StatusBarSettings;
+
+/// Every setting the settings app manages, gathered into a single
+/// serializable bundle so a device can be backed up and restored in one
+/// shot (e.g. before a firmware update, or to clone config to a fleet of
+/// devices).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SettingsBundle {
+    #[serde(default)]
+    pub status_bar: StatusBarSettings,
+}
+
+impl SettingsBundle {
+    /// Serialize the bundle to YAML and write it to `path`.
+    pub fn export_to_file(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let yaml = serde_yaml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, yaml).map_err(|e| e.to_string())
+    }
+
+    /// Read and parse a bundle previously written by [`export_to_file`].
+    pub fn import_from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_yaml::from_str(&contents).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_a_file() {
+        let dir = std::env::temp_dir().join("mechanix-settings-bundle-test");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bundle.yml");
+
+        let bundle = SettingsBundle {
+            status_bar: StatusBarSettings {
+                scale_factor: 1.5,
+                ..Default::default()
+            },
+        };
+        bundle.export_to_file(&path).unwrap();
+
+        let imported = SettingsBundle::import_from_file(&path).unwrap();
+        assert_eq!(imported.status_bar.scale_factor, 1.5);
+    }
+}
@@ -0,0 +1,65 @@
+use brightness::Brightness;
+
+use super::debounce::DebouncedSlider;
+
+/// Icon tier for the brightness tile, swapped as the slider crosses
+/// thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrightnessTier {
+    Low,
+    Medium,
+    High,
+}
+
+impl BrightnessTier {
+    pub fn from_percent(percent: u8) -> Self {
+        match percent {
+            0..=33 => BrightnessTier::Low,
+            34..=66 => BrightnessTier::Medium,
+            _ => BrightnessTier::High,
+        }
+    }
+}
+
+/// Drives the settings panel's brightness slider: debounces drag events
+/// and forwards the throttled value to the backlight sysfs control.
+pub struct BrightnessSlider {
+    brightness: Brightness,
+    debounced: DebouncedSlider,
+}
+
+impl BrightnessSlider {
+    pub fn new(brightness: Brightness) -> Self {
+        Self { brightness, debounced: DebouncedSlider::new() }
+    }
+
+    /// Called on every slider drag event; applies the new value to the
+    /// backlight only when the debounce interval allows it.
+    pub fn drag(&mut self, percent: u8, now: std::time::Instant) -> Result<(), brightness::BrightnessError> {
+        match self.debounced.drag(percent, now) {
+            Some(value) => self.brightness.set_brightness_percent(value),
+            None => Ok(()),
+        }
+    }
+
+    /// Called on release, to make sure the slider's final position is
+    /// applied even if it arrived within the debounce window.
+    pub fn release(&mut self) -> Result<(), brightness::BrightnessError> {
+        match self.debounced.flush() {
+            Some(value) => self.brightness.set_brightness_percent(value),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tier_boundaries_map_to_the_right_icon() {
+        assert_eq!(BrightnessTier::from_percent(0), BrightnessTier::Low);
+        assert_eq!(BrightnessTier::from_percent(50), BrightnessTier::Medium);
+        assert_eq!(BrightnessTier::from_percent(100), BrightnessTier::High);
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+/// Minimum time between two applied drag updates, so dragging doesn't
+/// spam the underlying sysfs/PulseAudio call on every pixel of motion.
+pub const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Collapses rapid slider drag updates into occasional calls, always
+/// keeping the most recent value so nothing is lost - only the cadence of
+/// applying it is throttled.
+#[derive(Debug, Default)]
+pub struct DebouncedSlider {
+    pending: Option<u8>,
+    last_applied_at: Option<Instant>,
+}
+
+impl DebouncedSlider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a drag update. Returns the value to apply now if the
+    /// debounce interval has elapsed since the last applied value,
+    /// otherwise queues it for the next call or [`Self::flush`].
+    pub fn drag(&mut self, percent: u8, now: Instant) -> Option<u8> {
+        self.pending = Some(percent);
+        let ready = self
+            .last_applied_at
+            .is_none_or(|at| now.duration_since(at) >= DEBOUNCE_INTERVAL);
+        if ready {
+            self.last_applied_at = Some(now);
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Applies whatever drag value is still queued, e.g. on release, so
+    /// the slider's final position is never silently dropped.
+    pub fn flush(&mut self) -> Option<u8> {
+        self.pending.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_drag_applies_immediately() {
+        let mut slider = DebouncedSlider::new();
+        assert_eq!(slider.drag(50, Instant::now()), Some(50));
+    }
+
+    #[test]
+    fn rapid_drags_within_interval_are_queued_not_applied() {
+        let mut slider = DebouncedSlider::new();
+        let start = Instant::now();
+        slider.drag(50, start);
+        assert_eq!(slider.drag(60, start + Duration::from_millis(10)), None);
+    }
+
+    #[test]
+    fn flush_returns_the_last_queued_value() {
+        let mut slider = DebouncedSlider::new();
+        let start = Instant::now();
+        slider.drag(50, start);
+        slider.drag(60, start + Duration::from_millis(10));
+        assert_eq!(slider.flush(), Some(60));
+        assert_eq!(slider.flush(), None);
+    }
+}
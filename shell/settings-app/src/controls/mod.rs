@@ -0,0 +1,13 @@
+pub mod brightness;
+pub mod debounce;
+pub mod power_profile;
+pub mod rotation;
+pub mod sound;
+pub mod theme;
+
+pub use brightness::{BrightnessSlider, BrightnessTier};
+pub use debounce::DebouncedSlider;
+pub use power_profile::{next_profile, PowerProfileControl, PowerProfileError};
+pub use rotation::{RotationIcon, RotationToggle};
+pub use sound::{VolumeSlider, VolumeTier};
+pub use theme::{ThemeControl, ThemeControlError};
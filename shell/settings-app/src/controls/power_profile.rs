@@ -0,0 +1,72 @@
+use std::fmt;
+
+use power::{PowerProfile, PowerProfilesProxy, UnknownProfile};
+
+#[derive(Debug)]
+pub enum PowerProfileError {
+    Dbus(zbus::Error),
+    Unknown(UnknownProfile),
+}
+
+impl fmt::Display for PowerProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerProfileError::Dbus(err) => write!(f, "power-profiles-daemon call failed: {err}"),
+            PowerProfileError::Unknown(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for PowerProfileError {}
+
+impl From<zbus::Error> for PowerProfileError {
+    fn from(err: zbus::Error) -> Self {
+        PowerProfileError::Dbus(err)
+    }
+}
+
+/// Drives the settings panel's power-profile tile: cycles through
+/// performance/balanced/power-saver and pushes the choice to
+/// power-profiles-daemon over D-Bus.
+pub struct PowerProfileControl<'a> {
+    proxy: PowerProfilesProxy<'a>,
+}
+
+impl<'a> PowerProfileControl<'a> {
+    pub fn new(proxy: PowerProfilesProxy<'a>) -> Self {
+        Self { proxy }
+    }
+
+    pub async fn current(&self) -> Result<PowerProfile, PowerProfileError> {
+        let value = self.proxy.active_profile().await?;
+        PowerProfile::parse(&value).map_err(PowerProfileError::Unknown)
+    }
+
+    pub async fn set(&self, profile: PowerProfile) -> Result<(), PowerProfileError> {
+        self.proxy.set_active_profile(profile.as_str().to_string()).await?;
+        Ok(())
+    }
+}
+
+/// Cycles to the next profile in the standard power-saver -> balanced ->
+/// performance -> power-saver rotation, for a tile that cycles on tap
+/// rather than opening a picker.
+pub fn next_profile(current: PowerProfile) -> PowerProfile {
+    match current {
+        PowerProfile::PowerSaver => PowerProfile::Balanced,
+        PowerProfile::Balanced => PowerProfile::Performance,
+        PowerProfile::Performance => PowerProfile::PowerSaver,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_through_all_three_profiles_and_back() {
+        assert_eq!(next_profile(PowerProfile::PowerSaver), PowerProfile::Balanced);
+        assert_eq!(next_profile(PowerProfile::Balanced), PowerProfile::Performance);
+        assert_eq!(next_profile(PowerProfile::Performance), PowerProfile::PowerSaver);
+    }
+}
@@ -0,0 +1,81 @@
+use rotation::{DisplayManager, Orientation, RotationLock};
+
+/// Which icon the rotation tile shows, derived from the current
+/// orientation reported by the compositor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationIcon {
+    Portrait,
+    Landscape,
+}
+
+/// Drives the settings panel's rotation-lock tile: flips a [`RotationLock`]
+/// and reflects the compositor's current orientation (via
+/// `DisplayManager::get_rotation_state`) in the icon. While locked, the
+/// current orientation is held regardless of further accelerometer input.
+pub struct RotationToggle {
+    display: Box<dyn DisplayManager>,
+    lock: RotationLock,
+}
+
+impl RotationToggle {
+    pub fn new(display: Box<dyn DisplayManager>) -> Self {
+        Self { display, lock: RotationLock::new() }
+    }
+
+    /// Flips the lock and returns the new state.
+    pub fn toggle(&mut self) -> bool {
+        self.lock.toggle()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.lock.is_locked()
+    }
+
+    /// The compositor's current orientation, falling back to `Normal` if
+    /// the backend call fails rather than leaving the tile blank.
+    pub fn current_orientation(&self) -> Orientation {
+        self.display.get_rotation_state().unwrap_or_default()
+    }
+
+    pub fn icon(&self) -> RotationIcon {
+        match self.current_orientation() {
+            Orientation::Normal | Orientation::UpsideDown => RotationIcon::Portrait,
+            Orientation::Left | Orientation::Right => RotationIcon::Landscape,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rotation::RotationError;
+
+    struct FakeDisplay(Orientation);
+
+    impl DisplayManager for FakeDisplay {
+        fn change_rotation_state(&self, _orientation: Orientation) -> Result<(), RotationError> {
+            Ok(())
+        }
+
+        fn get_rotation_state(&self) -> Result<Orientation, RotationError> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn toggle_flips_the_lock() {
+        let mut toggle = RotationToggle::new(Box::new(FakeDisplay(Orientation::Normal)));
+        assert!(!toggle.is_locked());
+        assert!(toggle.toggle());
+        assert!(toggle.is_locked());
+    }
+
+    #[test]
+    fn icon_reflects_current_orientation() {
+        let toggle = RotationToggle::new(Box::new(FakeDisplay(Orientation::Left)));
+        assert_eq!(toggle.icon(), RotationIcon::Landscape);
+
+        let toggle = RotationToggle::new(Box::new(FakeDisplay(Orientation::Normal)));
+        assert_eq!(toggle.icon(), RotationIcon::Portrait);
+    }
+}
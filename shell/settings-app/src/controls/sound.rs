@@ -0,0 +1,68 @@
+use sound::{Sound, SoundError};
+
+use super::debounce::DebouncedSlider;
+
+/// Icon tier for the volume tile, swapped as the slider crosses
+/// thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeTier {
+    Muted,
+    Low,
+    Medium,
+    High,
+}
+
+impl VolumeTier {
+    pub fn from_percent(percent: u8) -> Self {
+        match percent {
+            0 => VolumeTier::Muted,
+            1..=33 => VolumeTier::Low,
+            34..=66 => VolumeTier::Medium,
+            _ => VolumeTier::High,
+        }
+    }
+}
+
+/// Drives the settings panel's volume slider: debounces drag events and
+/// forwards the throttled value to PulseAudio, so dragging doesn't spam
+/// `set_output_volumes`.
+pub struct VolumeSlider {
+    sound: Sound,
+    sink_name: String,
+    debounced: DebouncedSlider,
+}
+
+impl VolumeSlider {
+    pub fn new(sound: Sound, sink_name: String) -> Self {
+        Self { sound, sink_name, debounced: DebouncedSlider::new() }
+    }
+
+    pub fn drag(&mut self, percent: u8, now: std::time::Instant) -> Result<(), SoundError> {
+        match self.debounced.drag(percent, now) {
+            Some(value) => self.sound.set_output_volumes(&self.sink_name, value),
+            None => Ok(()),
+        }
+    }
+
+    /// Called on release, to make sure the slider's final position is
+    /// applied even if it arrived within the debounce window.
+    pub fn release(&mut self) -> Result<(), SoundError> {
+        match self.debounced.flush() {
+            Some(value) => self.sound.set_output_volumes(&self.sink_name, value),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tier_boundaries_map_to_the_right_icon() {
+        assert_eq!(VolumeTier::from_percent(0), VolumeTier::Muted);
+        assert_eq!(VolumeTier::from_percent(20), VolumeTier::Low);
+        assert_eq!(VolumeTier::from_percent(50), VolumeTier::Medium);
+        assert_eq!(VolumeTier::from_percent(100), VolumeTier::High);
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+
+use theme::{ThemeMode, ThemeProxy, UnknownThemeMode};
+
+#[derive(Debug)]
+pub enum ThemeControlError {
+    Dbus(zbus::Error),
+    Unknown(UnknownThemeMode),
+}
+
+impl fmt::Display for ThemeControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeControlError::Dbus(err) => write!(f, "theme service call failed: {err}"),
+            ThemeControlError::Unknown(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeControlError {}
+
+impl From<zbus::Error> for ThemeControlError {
+    fn from(err: zbus::Error) -> Self {
+        ThemeControlError::Dbus(err)
+    }
+}
+
+/// Drives the settings panel's theme tile: reads and sets the system
+/// dark/light/auto preference over `com.mecha.Theme`.
+pub struct ThemeControl<'a> {
+    proxy: ThemeProxy<'a>,
+}
+
+impl<'a> ThemeControl<'a> {
+    pub fn new(proxy: ThemeProxy<'a>) -> Self {
+        Self { proxy }
+    }
+
+    pub async fn current(&self) -> Result<ThemeMode, ThemeControlError> {
+        let value = self.proxy.get_mode().await?;
+        ThemeMode::parse(&value).map_err(ThemeControlError::Unknown)
+    }
+
+    pub async fn set(&self, mode: ThemeMode) -> Result<(), ThemeControlError> {
+        self.proxy.set_mode(mode.as_str().to_string()).await?;
+        Ok(())
+    }
+}
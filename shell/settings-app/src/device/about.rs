@@ -0,0 +1,44 @@
+use super::model::StorageInfo;
+
+/// Human-readable `used / total` with a binary (GiB) unit, e.g.
+/// `"12.3 GiB / 58.0 GiB"`, for the About screen's storage row.
+pub fn format_storage(info: &StorageInfo) -> String {
+    format!(
+        "{} / {}",
+        format_bytes(info.used_bytes),
+        format_bytes(info.total_bytes)
+    )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+    format!("{:.1} GiB", bytes as f64 / GIB)
+}
+
+/// About screen fallback for any field that hasn't finished loading yet,
+/// matching the convention the provisioning fields already use.
+pub const UNKNOWN: &str = "-";
+
+/// Renders `info`, or [`UNKNOWN`] if the storage fetch hasn't completed.
+pub fn storage_row(info: Option<&StorageInfo>) -> String {
+    info.map(format_storage).unwrap_or_else(|| UNKNOWN.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_used_over_total_in_gib() {
+        let info = StorageInfo {
+            total_bytes: 58 * 1024 * 1024 * 1024,
+            used_bytes: 12 * 1024 * 1024 * 1024 + 300 * 1024 * 1024,
+        };
+        assert_eq!(format_storage(&info), "12.3 GiB / 58.0 GiB");
+    }
+
+    #[test]
+    fn missing_storage_falls_back_to_unknown() {
+        assert_eq!(storage_row(None), "-");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod about;
+pub mod model;
+
+pub use model::{Context, DeviceModel, MemInfo, OsInfo, ProvisionStatus, StorageInfo};
@@ -0,0 +1,269 @@
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+use zbus::Connection;
+
+/// Background runtime the settings-app UI thread doesn't run its own, used
+/// for [`DeviceModel::update`]'s fire-and-forget fetches.
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| Runtime::new().expect("failed to start settings-app background runtime"))
+}
+
+/// A value populated by a background fetch and polled by the UI. Cheap to
+/// clone so the spawned task and the widget tree can share a handle to the
+/// same slot; starts at `T::default()` (typically `None`) until the first
+/// fetch completes.
+#[derive(Debug, Default)]
+pub struct Context<T>(Arc<Mutex<T>>);
+
+impl<T> Clone for Context<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Clone + Default> Context<T> {
+    pub fn get(&self) -> T {
+        self.0.lock().unwrap().clone()
+    }
+
+    pub fn set(&self, value: T) {
+        *self.0.lock().unwrap() = value;
+    }
+}
+
+/// Whether this device has completed onboarding with the identity service,
+/// and the id/name it was provisioned with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvisionStatus {
+    pub is_provisioned: bool,
+    pub device_id: Option<String>,
+    pub device_name: Option<String>,
+}
+
+/// OS info as reported by `uname -srm`: `name` is the kernel name,
+/// `version` the kernel release, and `kernel` the machine hardware name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OsInfo {
+    pub name: String,
+    pub version: String,
+    pub kernel: String,
+}
+
+/// Root filesystem disk usage, in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StorageInfo {
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+}
+
+/// RAM usage, in bytes, as reported by `/proc/meminfo`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemInfo {
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+/// Thin client for the identity D-Bus service (`com.mecha.Identity`) that
+/// tracks device provisioning. It comes up slightly after the rest of the
+/// system at boot, so callers shouldn't treat a connection failure here as
+/// fatal - see [`DeviceModel::update`].
+struct IdentityClient {
+    proxy: zbus::Proxy<'static>,
+}
+
+impl IdentityClient {
+    async fn new() -> zbus::Result<Self> {
+        let connection = Connection::system().await?;
+        let proxy = zbus::Proxy::new(
+            &connection,
+            "com.mecha.Identity",
+            "/com/mecha/Identity",
+            "com.mecha.Identity",
+        )
+        .await?;
+        Ok(Self { proxy })
+    }
+
+    async fn get_machine_provision_status(&self) -> zbus::Result<ProvisionStatus> {
+        let is_provisioned: bool = self.proxy.call("IsProvisioned", &()).await?;
+        if !is_provisioned {
+            return Ok(ProvisionStatus::default());
+        }
+
+        let device_id: Option<String> = self.proxy.call("MachineId", &()).await.ok();
+        let device_name: Option<String> = self.proxy.call("MachineName", &()).await.ok();
+        Ok(ProvisionStatus {
+            is_provisioned,
+            device_id,
+            device_name,
+        })
+    }
+}
+
+/// Fetches `uname -srm` (kernel name, version, machine) and splits it into
+/// [`OsInfo`]'s fields.
+fn fetch_os_info() -> Option<OsInfo> {
+    let output = std::process::Command::new("uname").arg("-srm").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let mut parts = text.trim().splitn(3, ' ');
+    Some(OsInfo {
+        name: parts.next().unwrap_or_default().to_string(),
+        version: parts.next().unwrap_or_default().to_string(),
+        kernel: parts.next().unwrap_or_default().to_string(),
+    })
+}
+
+/// Disk usage for the root filesystem, via `statvfs("/")`.
+fn fetch_storage_info() -> Option<StorageInfo> {
+    let stat = rustix::fs::statvfs("/").ok()?;
+    let total_bytes = stat.f_frsize.saturating_mul(stat.f_blocks) as u64;
+    let free_bytes = stat.f_frsize.saturating_mul(stat.f_bfree) as u64;
+    Some(StorageInfo {
+        total_bytes,
+        used_bytes: total_bytes.saturating_sub(free_bytes),
+    })
+}
+
+/// Parses `MemTotal`/`MemAvailable` (in kB) out of `/proc/meminfo`.
+fn fetch_mem_info() -> Option<MemInfo> {
+    let contents = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let mut total_kb = None;
+    let mut available_kb = None;
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("MemTotal:") {
+            total_kb = parse_meminfo_kb(value);
+        } else if let Some(value) = line.strip_prefix("MemAvailable:") {
+            available_kb = parse_meminfo_kb(value);
+        }
+    }
+    Some(MemInfo {
+        total_bytes: total_kb? * 1024,
+        available_bytes: available_kb? * 1024,
+    })
+}
+
+fn parse_meminfo_kb(value: &str) -> Option<u64> {
+    value.trim().trim_end_matches(" kB").trim().parse().ok()
+}
+
+/// Parses the first field of `/proc/uptime` (seconds since boot).
+fn fetch_uptime() -> Option<Duration> {
+    let contents = std::fs::read_to_string("/proc/uptime").ok()?;
+    let seconds: f64 = contents.split_whitespace().next()?.parse().ok()?;
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Backs the settings app's About screen: device provisioning status, OS
+/// info, disk usage, memory, and uptime, each fetched in the background so
+/// the screen can render immediately and fill in fields as they arrive.
+#[derive(Debug, Default)]
+pub struct DeviceModel {
+    pub provision_status: Context<Option<ProvisionStatus>>,
+    pub os_info: Context<Option<OsInfo>>,
+    pub storage: Context<Option<StorageInfo>>,
+    pub memory: Context<Option<MemInfo>>,
+    pub uptime: Context<Option<Duration>>,
+}
+
+impl DeviceModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns the background fetches that populate every field. `os_info`,
+    /// `storage`, `memory`, and `uptime` only need the local machine; the
+    /// provisioning fields go through [`Self::refresh`], which retries the
+    /// identity service and gates the id/name on `is_provisioned`.
+    pub fn update(&self) {
+        let os_info = self.os_info.clone();
+        runtime().spawn(async move {
+            os_info.set(fetch_os_info());
+        });
+
+        let storage = self.storage.clone();
+        runtime().spawn(async move {
+            storage.set(fetch_storage_info());
+        });
+
+        let memory = self.memory.clone();
+        runtime().spawn(async move {
+            memory.set(fetch_mem_info());
+        });
+
+        let uptime = self.uptime.clone();
+        runtime().spawn(async move {
+            uptime.set(fetch_uptime());
+        });
+
+        self.refresh();
+    }
+
+    /// Re-fetches the provisioning fields, for a pull-to-refresh gesture
+    /// or an explicit retry button. Retries a few times with backoff
+    /// first, since at boot the identity service can come up slightly
+    /// after the settings app does.
+    pub fn refresh(&self) {
+        let provision_status = self.provision_status.clone();
+        runtime().spawn(async move {
+            if let Some(status) = fetch_provision_status_with_retry().await {
+                provision_status.set(Some(status));
+            }
+        });
+    }
+}
+
+/// How many times [`DeviceModel::refresh`] retries the identity service
+/// before giving up, and the base delay between attempts (the n-th retry
+/// waits n times the base delay).
+const PROVISION_FETCH_ATTEMPTS: u32 = 4;
+const PROVISION_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+async fn fetch_provision_status_with_retry() -> Option<ProvisionStatus> {
+    for attempt in 0..PROVISION_FETCH_ATTEMPTS {
+        let outcome = match IdentityClient::new().await {
+            Ok(client) => client.get_machine_provision_status().await,
+            Err(err) => Err(err),
+        };
+        match outcome {
+            Ok(status) => return Some(status),
+            Err(err) => tracing::warn!(%err, attempt, "failed to fetch provision status"),
+        }
+
+        if attempt + 1 < PROVISION_FETCH_ATTEMPTS {
+            tokio::time::sleep(PROVISION_RETRY_BASE_DELAY * (attempt + 1)).await;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_starts_at_default_and_reflects_sets() {
+        let context: Context<Option<u32>> = Context::default();
+        assert_eq!(context.get(), None);
+        context.set(Some(42));
+        assert_eq!(context.get(), Some(42));
+    }
+
+    #[test]
+    fn context_clones_share_the_same_slot() {
+        let context: Context<Option<u32>> = Context::default();
+        let handle = context.clone();
+        handle.set(Some(7));
+        assert_eq!(context.get(), Some(7));
+    }
+
+    #[test]
+    fn parses_meminfo_kb_value() {
+        assert_eq!(parse_meminfo_kb("  16384 kB"), Some(16384));
+    }
+}
@@ -0,0 +1,14 @@
+pub mod bluetooth;
+pub mod bundle;
+pub mod controls;
+pub mod device;
+pub mod networking;
+
+pub use bluetooth::{BluetoothDevice, BluetoothModel, BluetoothScreen};
+pub use bundle::SettingsBundle;
+pub use controls::{
+    next_profile, BrightnessSlider, BrightnessTier, PowerProfileControl, PowerProfileError, RotationIcon,
+    RotationToggle, ThemeControl, ThemeControlError, VolumeSlider, VolumeTier,
+};
+pub use device::DeviceModel;
+pub use networking::{NetworkingScreen, WifiNetwork};
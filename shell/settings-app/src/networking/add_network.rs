@@ -0,0 +1,56 @@
+/// State for manually adding a network that isn't in the scan list,
+/// because it's hidden (not broadcasting its SSID).
+#[derive(Debug, Clone, Default)]
+pub struct AddNetworkState {
+    pub is_open: bool,
+    pub ssid: String,
+    pub password: String,
+    pub is_hidden: bool,
+    pub error: Option<String>,
+}
+
+impl AddNetworkState {
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.ssid.clear();
+        self.password.clear();
+        self.is_hidden = false;
+        self.error = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    pub fn toggle_hidden(&mut self) {
+        self.is_hidden = !self.is_hidden;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_resets_previous_state() {
+        let mut state = AddNetworkState {
+            ssid: "stale".to_string(),
+            is_hidden: true,
+            error: Some("stale error".to_string()),
+            ..Default::default()
+        };
+        state.open();
+        assert!(state.ssid.is_empty());
+        assert!(!state.is_hidden);
+        assert!(state.error.is_none());
+    }
+
+    #[test]
+    fn toggle_hidden_flips_flag() {
+        let mut state = AddNetworkState::default();
+        state.toggle_hidden();
+        assert!(state.is_hidden);
+        state.toggle_hidden();
+        assert!(!state.is_hidden);
+    }
+}
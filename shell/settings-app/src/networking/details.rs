@@ -0,0 +1,49 @@
+use networkmanager::{format_ipv4, IP4ConfigProxy};
+use zbus::zvariant::OwnedObjectPath;
+use zbus::Connection;
+
+/// Live details for the currently connected network, shown on the
+/// "Network Details" screen. Previously this was populated with
+/// placeholder strings; it now reads the active `IP4Config` object.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkDetails {
+    pub ip_address: Option<String>,
+    pub gateway: Option<String>,
+}
+
+impl NetworkDetails {
+    pub async fn fetch(
+        connection: &Connection,
+        ip4_config_path: OwnedObjectPath,
+    ) -> zbus::Result<Self> {
+        let proxy = IP4ConfigProxy::builder(connection)
+            .path(ip4_config_path)?
+            .build()
+            .await?;
+
+        let addresses = proxy.addresses().await?;
+        let ip_address = addresses
+            .first()
+            .map(|(address, _prefix, _gateway)| format_ipv4(*address));
+        let gateway = proxy.gateway().await.ok().filter(|g| !g.is_empty());
+
+        Ok(Self {
+            ip_address,
+            gateway,
+        })
+    }
+
+    /// Disconnects from the currently active network without forgetting
+    /// it. The "Disconnect" button on this screen should call this
+    /// instead of removing the saved network outright.
+    pub async fn disconnect_active(connection: &Connection) -> zbus::Result<()> {
+        let proxy = zbus::Proxy::new(
+            connection,
+            "com.mecha.Wireless",
+            "/com/mecha/Wireless",
+            "com.mecha.Wireless",
+        )
+        .await?;
+        proxy.call("DisconnectActive", &()).await
+    }
+}
@@ -0,0 +1,68 @@
+use super::KnownNetwork;
+
+/// State for the "Forget this network?" confirmation shown before a saved
+/// network is removed, so a stray tap on the delete icon doesn't drop it
+/// immediately.
+#[derive(Debug, Clone, Default)]
+pub struct ForgetNetworkModalState {
+    network: Option<KnownNetwork>,
+}
+
+impl ForgetNetworkModalState {
+    pub fn is_open(&self) -> bool {
+        self.network.is_some()
+    }
+
+    pub fn network(&self) -> Option<&KnownNetwork> {
+        self.network.as_ref()
+    }
+
+    pub fn open_for(&mut self, network: KnownNetwork) {
+        self.network = Some(network);
+    }
+
+    pub fn close(&mut self) {
+        self.network = None;
+    }
+
+    /// Closes the modal and returns the network to forget, for the caller
+    /// to pass to [`super::WirelessModel::forget_saved_network`]. Returns
+    /// `None` if the modal wasn't open.
+    pub fn confirm(&mut self) -> Option<KnownNetwork> {
+        self.network.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network(id: u32) -> KnownNetwork {
+        KnownNetwork { network_id: id, ssid: "HomeNet".to_string(), priority: 0 }
+    }
+
+    #[test]
+    fn delete_icon_opens_modal_without_forgetting() {
+        let mut state = ForgetNetworkModalState::default();
+        state.open_for(network(3));
+        assert!(state.is_open());
+        assert_eq!(state.network().unwrap().network_id, 3);
+    }
+
+    #[test]
+    fn confirm_closes_modal_and_returns_the_network() {
+        let mut state = ForgetNetworkModalState::default();
+        state.open_for(network(3));
+        let confirmed = state.confirm();
+        assert_eq!(confirmed.unwrap().network_id, 3);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn close_discards_without_forgetting() {
+        let mut state = ForgetNetworkModalState::default();
+        state.open_for(network(3));
+        state.close();
+        assert!(!state.is_open());
+    }
+}
@@ -0,0 +1,15 @@
+pub mod add_network;
+pub mod details;
+pub mod forget_modal;
+pub mod password_modal;
+pub mod screen;
+pub mod wired;
+pub mod wireless_model;
+
+pub use add_network::AddNetworkState;
+pub use details::NetworkDetails;
+pub use forget_modal::ForgetNetworkModalState;
+pub use password_modal::PasswordModalState;
+pub use screen::{NetworkingScreen, WifiNetwork};
+pub use wired::WiredStatus;
+pub use wireless_model::{available_networks_disabled, KnownNetwork, WirelessModel};
@@ -0,0 +1,73 @@
+use super::WifiNetwork;
+
+/// State for the "enter password" modal shown before connecting to a
+/// secured network.
+#[derive(Debug, Clone, Default)]
+pub struct PasswordModalState {
+    pub network: Option<WifiNetwork>,
+    pub password: String,
+    pub error: Option<String>,
+}
+
+impl PasswordModalState {
+    pub fn is_open(&self) -> bool {
+        self.network.is_some()
+    }
+
+    /// Open the modal for `network`, or skip it and let the caller connect
+    /// directly if the network is open.
+    pub fn open_for(&mut self, network: WifiNetwork) -> bool {
+        if !network.is_secured {
+            return false;
+        }
+        self.password.clear();
+        self.error = None;
+        self.network = Some(network);
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.network = None;
+        self.password.clear();
+        self.error = None;
+    }
+
+    pub fn set_password(&mut self, password: String) {
+        self.password = password;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secured(ssid: &str) -> WifiNetwork {
+        WifiNetwork {
+            ssid: ssid.to_string(),
+            strength: 80,
+            is_secured: true,
+        }
+    }
+
+    fn open(ssid: &str) -> WifiNetwork {
+        WifiNetwork {
+            ssid: ssid.to_string(),
+            strength: 80,
+            is_secured: false,
+        }
+    }
+
+    #[test]
+    fn secured_network_opens_modal() {
+        let mut state = PasswordModalState::default();
+        assert!(state.open_for(secured("HomeNet")));
+        assert!(state.is_open());
+    }
+
+    #[test]
+    fn open_network_does_not_open_modal() {
+        let mut state = PasswordModalState::default();
+        assert!(!state.open_for(open("CoffeeShop")));
+        assert!(!state.is_open());
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use networkmanager::AccessPointInfo;
+use zbus::Connection;
+
+/// A Wi-Fi network as listed in the networking screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WifiNetwork {
+    pub ssid: String,
+    pub strength: u8,
+    pub is_secured: bool,
+}
+
+impl From<AccessPointInfo> for WifiNetwork {
+    fn from(info: AccessPointInfo) -> Self {
+        Self {
+            ssid: info.ssid,
+            strength: info.strength,
+            is_secured: info.is_secured,
+        }
+    }
+}
+
+/// The networking screen in the settings app. Previously this rendered a
+/// static mock list; `scan` now talks to NetworkManager over D-Bus for the
+/// real list of nearby access points.
+pub struct NetworkingScreen {
+    device_path: zbus::zvariant::OwnedObjectPath,
+}
+
+impl NetworkingScreen {
+    pub fn new(device_path: zbus::zvariant::OwnedObjectPath) -> Self {
+        Self { device_path }
+    }
+
+    /// Request a rescan and return the deduplicated, strongest-first list
+    /// of visible networks. A single SSID broadcast by several access
+    /// points (mesh APs, roaming) is folded into its strongest reading.
+    pub async fn scan(&self, connection: &Connection) -> zbus::Result<Vec<WifiNetwork>> {
+        let device = networkmanager::WirelessDeviceProxy::builder(connection)
+            .path(self.device_path.clone())?
+            .build()
+            .await?;
+
+        device.request_scan(Default::default()).await.ok();
+
+        let ap_paths = device.get_all_access_points().await?;
+        let mut networks = Vec::with_capacity(ap_paths.len());
+        for path in ap_paths {
+            let ap = networkmanager::AccessPointProxy::builder(connection)
+                .path(path)?
+                .build()
+                .await?;
+            let info = ap.info().await?;
+            if !info.ssid.is_empty() {
+                networks.push(WifiNetwork::from(info));
+            }
+        }
+
+        networks.sort_by_key(|network| std::cmp::Reverse(network.strength));
+
+        let mut seen = HashSet::new();
+        networks.retain(|network| seen.insert(network.ssid.clone()));
+        Ok(networks)
+    }
+
+    /// Connect to `network`, supplying `password` for secured networks.
+    /// Delegates to NetworkManager's `AddAndActivateConnection`, which
+    /// creates (or reuses) a saved connection profile and brings it up.
+    pub async fn connect(
+        &self,
+        connection: &Connection,
+        network: &WifiNetwork,
+        password: Option<&str>,
+    ) -> zbus::Result<()> {
+        use zbus::zvariant::Value;
+        use std::collections::HashMap;
+
+        let mut wireless_security: HashMap<&str, Value> = HashMap::new();
+        if let Some(password) = password {
+            wireless_security.insert("key-mgmt", Value::new("wpa-psk"));
+            wireless_security.insert("psk", Value::new(password));
+        }
+
+        let mut wireless: HashMap<&str, Value> = HashMap::new();
+        wireless.insert("ssid", Value::new(network.ssid.as_bytes().to_vec()));
+
+        let mut connection_settings: HashMap<&str, HashMap<&str, Value>> = HashMap::new();
+        connection_settings.insert("802-11-wireless", wireless);
+        if !wireless_security.is_empty() {
+            connection_settings.insert("802-11-wireless-security", wireless_security);
+        }
+
+        let proxy = zbus::Proxy::new(
+            connection,
+            "org.freedesktop.NetworkManager",
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+        )
+        .await?;
+
+        proxy
+            .call_method(
+                "AddAndActivateConnection",
+                &(
+                    connection_settings,
+                    self.device_path.clone(),
+                    zbus::zvariant::ObjectPath::try_from("/")?,
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use networkmanager::{format_ipv4, DeviceProxy, IP4ConfigProxy, WiredDeviceProxy};
+use zbus::zvariant::OwnedObjectPath;
+use zbus::Connection;
+
+/// `NM_DEVICE_STATE_ACTIVATED` from the NetworkManager D-Bus API - the
+/// only state value we care about for a simple connected/not row.
+const NM_DEVICE_STATE_ACTIVATED: u32 = 100;
+
+/// Status of a wired (Ethernet) device, shown on the networking screen
+/// alongside the Wi-Fi list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WiredStatus {
+    pub is_connected: bool,
+    pub has_carrier: bool,
+    pub ip_address: Option<String>,
+}
+
+impl WiredStatus {
+    pub async fn fetch(connection: &Connection, device_path: OwnedObjectPath) -> zbus::Result<Self> {
+        let device = DeviceProxy::builder(connection)
+            .path(device_path.clone())?
+            .build()
+            .await?;
+        let wired = WiredDeviceProxy::builder(connection)
+            .path(device_path)?
+            .build()
+            .await?;
+
+        let is_connected = device.state().await.unwrap_or(0) == NM_DEVICE_STATE_ACTIVATED;
+        let has_carrier = wired.carrier().await.unwrap_or(false);
+
+        let ip_address = if is_connected {
+            match device.ip4_config().await {
+                Ok(path) if path.as_str() != "/" => {
+                    let ip4 = IP4ConfigProxy::builder(connection).path(path)?.build().await?;
+                    ip4.addresses()
+                        .await
+                        .ok()
+                        .and_then(|addresses| addresses.first().map(|(address, ..)| format_ipv4(*address)))
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            is_connected,
+            has_carrier,
+            ip_address,
+        })
+    }
+
+    /// Row label for the networking screen.
+    pub fn status_label(&self) -> &'static str {
+        if self.is_connected {
+            "Ethernet — Connected"
+        } else if self.has_carrier {
+            "Ethernet — Carrier detected"
+        } else {
+            "Ethernet — Disconnected"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connected_label() {
+        let status = WiredStatus {
+            is_connected: true,
+            has_carrier: true,
+            ip_address: Some("192.168.1.10".to_string()),
+        };
+        assert_eq!(status.status_label(), "Ethernet — Connected");
+    }
+
+    #[test]
+    fn carrier_but_not_connected_label() {
+        let status = WiredStatus {
+            is_connected: false,
+            has_carrier: true,
+            ip_address: None,
+        };
+        assert_eq!(status.status_label(), "Ethernet — Carrier detected");
+    }
+
+    #[test]
+    fn disconnected_label() {
+        let status = WiredStatus::default();
+        assert_eq!(status.status_label(), "Ethernet — Disconnected");
+    }
+}
@@ -0,0 +1,94 @@
+use zbus::Connection;
+
+/// A saved network as shown on the settings-app saved-networks screen,
+/// drag-reorderable by `priority`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownNetwork {
+    pub network_id: u32,
+    pub ssid: String,
+    pub priority: i32,
+}
+
+/// Client for `com.mecha.Wireless`'s saved-network methods.
+pub struct WirelessModel;
+
+impl WirelessModel {
+    async fn proxy(connection: &Connection) -> zbus::Result<zbus::Proxy<'_>> {
+        zbus::Proxy::new(
+            connection,
+            "com.mecha.Wireless",
+            "/com/mecha/Wireless",
+            "com.mecha.Wireless",
+        )
+        .await
+    }
+
+    pub async fn known_networks(connection: &Connection) -> zbus::Result<Vec<KnownNetwork>> {
+        let proxy = Self::proxy(connection).await?;
+        let networks: Vec<(u32, String, i32)> = proxy.call("KnownNetworks", &()).await?;
+        Ok(networks
+            .into_iter()
+            .map(|(network_id, ssid, priority)| KnownNetwork {
+                network_id,
+                ssid,
+                priority,
+            })
+            .collect())
+    }
+
+    /// Reorders `network_id` by giving it `priority`. Higher priority
+    /// networks are preferred by wpa_supplicant when several are in range.
+    pub async fn set_network_priority(
+        connection: &Connection,
+        network_id: u32,
+        priority: i32,
+    ) -> zbus::Result<()> {
+        let proxy = Self::proxy(connection).await?;
+        proxy.call("SetNetworkPriority", &(network_id, priority)).await
+    }
+
+    /// Removes a saved network entirely, via `com.mecha.Wireless`'s
+    /// `Disconnect` method. Only call this after the caller has confirmed
+    /// via [`super::ForgetNetworkModalState`] - there's no undo.
+    pub async fn forget_saved_network(connection: &Connection, network_id: u32) -> zbus::Result<()> {
+        let proxy = Self::proxy(connection).await?;
+        proxy.call("Disconnect", &(network_id,)).await
+    }
+
+    /// Whether the Wi-Fi radio is currently on. Call this to reflect the
+    /// radio being turned off elsewhere (e.g. airplane mode), not just
+    /// after this model's own `enable`/`disable` calls.
+    pub async fn is_enabled(connection: &Connection) -> zbus::Result<bool> {
+        let proxy = Self::proxy(connection).await?;
+        proxy.call("Enabled", &()).await
+    }
+
+    pub async fn enable(connection: &Connection) -> zbus::Result<()> {
+        let proxy = Self::proxy(connection).await?;
+        proxy.call("Enable", &()).await
+    }
+
+    pub async fn disable(connection: &Connection) -> zbus::Result<()> {
+        let proxy = Self::proxy(connection).await?;
+        proxy.call("Disable", &()).await
+    }
+}
+
+/// Whether the available-networks section should render grayed out, given
+/// the radio's current enabled state. A thin named wrapper around `!enabled`
+/// so the networking screen's view code reads as intent rather than a bare
+/// negation.
+pub fn available_networks_disabled(radio_enabled: bool) -> bool {
+    !radio_enabled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn available_networks_are_grayed_out_only_while_radio_is_off() {
+        assert!(!available_networks_disabled(true));
+        assert!(available_networks_disabled(false));
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use idle_timer::{IdleTimer, IdleTimerHandle};
+use tokio::sync::mpsc;
+
+use crate::Navigation;
+
+/// Settings for auto-locking after inactivity. `timeout: None` means
+/// "never", matching [`crate::IdleReturnSettings`]'s convention.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoLockSettings {
+    pub timeout: Option<Duration>,
+}
+
+/// Drives the shell's idle-to-lock-screen behavior: on every input event
+/// the caller should call [`AutoLock::notice_input`]; if no input arrives
+/// before the configured timeout, the loop emits [`Navigation::LockScreen`],
+/// unless [`AutoLock::set_inhibited`] is currently holding it back, e.g.
+/// while media is playing.
+pub struct AutoLock {
+    idle_timer: IdleTimer,
+    inhibited: Arc<AtomicBool>,
+}
+
+impl AutoLock {
+    pub fn spawn(settings: AutoLockSettings, nav_tx: mpsc::Sender<Navigation>) -> Self {
+        let (idle_timer, handle) = IdleTimer::new(settings.timeout);
+        let inhibited = Arc::new(AtomicBool::new(false));
+        tokio::spawn(Self::watch(handle, inhibited.clone(), nav_tx));
+        Self { idle_timer, inhibited }
+    }
+
+    /// Reset the idle deadline; call this on any input event.
+    pub fn notice_input(&self) {
+        self.idle_timer.reset();
+    }
+
+    /// Suppresses locking while `inhibited` is set, e.g. while media is
+    /// playing or an app holds an explicit inhibitor. The idle timer keeps
+    /// counting underneath; if it elapses while inhibited, the loop just
+    /// waits another full timeout before checking again, so locking
+    /// resumes within one timeout period of uninhibiting.
+    pub fn set_inhibited(&self, inhibited: bool) {
+        self.inhibited.store(inhibited, Ordering::SeqCst);
+    }
+
+    async fn watch(mut handle: IdleTimerHandle, inhibited: Arc<AtomicBool>, nav_tx: mpsc::Sender<Navigation>) {
+        loop {
+            handle.wait_for_idle().await;
+            if inhibited.load(Ordering::SeqCst) {
+                continue;
+            }
+            if nav_tx.send(Navigation::LockScreen).await.is_err() {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use tokio::sync::mpsc;
+
+use crate::{KeyActionMap, Navigation, ShellAction};
+
+/// Runs the shell's main dispatch loop, waking only when a new key event or
+/// navigation request actually arrives instead of polling both channels on
+/// a fixed interval - there's nothing to do, and nothing to find, between
+/// events.
+///
+/// Returns once both channels are closed.
+pub async fn run_dispatch_loop(
+    mut key_rx: mpsc::Receiver<String>,
+    mut nav_rx: mpsc::Receiver<Navigation>,
+    key_actions: &KeyActionMap,
+    mut on_action: impl FnMut(ShellAction),
+    mut on_navigation: impl FnMut(Navigation),
+) {
+    let mut key_rx_closed = false;
+    let mut nav_rx_closed = false;
+
+    loop {
+        tokio::select! {
+            key_name = key_rx.recv(), if !key_rx_closed => {
+                match key_name {
+                    Some(key_name) => {
+                        if let Some(action) = key_actions.action_for(&key_name) {
+                            on_action(action);
+                        }
+                    }
+                    None => key_rx_closed = true,
+                }
+            }
+            nav = nav_rx.recv(), if !nav_rx_closed => {
+                match nav {
+                    Some(nav) => on_navigation(nav),
+                    None => nav_rx_closed = true,
+                }
+            }
+        }
+
+        if key_rx_closed && nav_rx_closed {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn dispatches_a_mapped_key_to_its_action() {
+        let (key_tx, key_rx) = mpsc::channel(1);
+        let (_nav_tx, nav_rx) = mpsc::channel(1);
+        let mut actions = Vec::new();
+
+        let key_actions = KeyActionMap::defaults();
+        key_tx.send("XF86PowerOff".to_string()).await.unwrap();
+        drop(key_tx);
+        drop(_nav_tx);
+
+        run_dispatch_loop(key_rx, nav_rx, &key_actions, |action| actions.push(action), |_| {}).await;
+
+        assert_eq!(actions, vec![ShellAction::PowerMenu]);
+    }
+
+    #[tokio::test]
+    async fn dispatches_navigation_events() {
+        let (_key_tx, key_rx) = mpsc::channel(1);
+        let (nav_tx, nav_rx) = mpsc::channel(1);
+        let mut navigations = Vec::new();
+
+        nav_tx.send(Navigation::Homescreen).await.unwrap();
+        drop(_key_tx);
+        drop(nav_tx);
+
+        run_dispatch_loop(key_rx, nav_rx, &KeyActionMap::defaults(), |_| {}, |nav| navigations.push(nav))
+            .await;
+
+        assert_eq!(navigations, vec![Navigation::Homescreen]);
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+/// Actions a hardware key press can trigger in the shell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellAction {
+    LockScreen,
+    PowerMenu,
+    VolumeUp,
+    VolumeDown,
+    BrightnessUp,
+    BrightnessDown,
+    ToggleMute,
+}
+
+/// A config-driven mapping of hardware key names (as reported by the input
+/// backend, e.g. `"XF86PowerOff"`, `"XF86AudioRaiseVolume"`) to
+/// [`ShellAction`]s, loaded from `settings.yml`.
+#[derive(Debug, Clone, Default)]
+pub struct KeyActionMap {
+    bindings: HashMap<String, ShellAction>,
+}
+
+impl KeyActionMap {
+    pub fn new(bindings: HashMap<String, ShellAction>) -> Self {
+        Self { bindings }
+    }
+
+    /// The repo's stock bindings, used when `settings.yml` doesn't override
+    /// them.
+    pub fn defaults() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert("XF86PowerOff".to_string(), ShellAction::PowerMenu);
+        bindings.insert("XF86AudioRaiseVolume".to_string(), ShellAction::VolumeUp);
+        bindings.insert("XF86AudioLowerVolume".to_string(), ShellAction::VolumeDown);
+        bindings.insert("XF86AudioMute".to_string(), ShellAction::ToggleMute);
+        bindings.insert(
+            "XF86MonBrightnessUp".to_string(),
+            ShellAction::BrightnessUp,
+        );
+        bindings.insert(
+            "XF86MonBrightnessDown".to_string(),
+            ShellAction::BrightnessDown,
+        );
+        Self::new(bindings)
+    }
+
+    pub fn action_for(&self, key_name: &str) -> Option<ShellAction> {
+        self.bindings.get(key_name).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_power_key_maps_to_power_menu() {
+        let map = KeyActionMap::defaults();
+        assert_eq!(map.action_for("XF86PowerOff"), Some(ShellAction::PowerMenu));
+    }
+
+    #[test]
+    fn unmapped_key_returns_none() {
+        let map = KeyActionMap::defaults();
+        assert_eq!(map.action_for("XF86Unknown"), None);
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use idle_timer::{IdleTimer, IdleTimerHandle};
+use launcher::AppManagerMessage;
+use tokio::sync::mpsc;
+
+pub mod auto_lock;
+pub mod dispatch;
+pub mod key_actions;
+
+pub use auto_lock::{AutoLock, AutoLockSettings};
+pub use dispatch::run_dispatch_loop;
+pub use key_actions::{KeyActionMap, ShellAction};
+
+/// Top-level navigation targets the shell event loop can switch to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Navigation {
+    Homescreen,
+    LockScreen,
+}
+
+/// What to do with the current foreground app when the idle timeout fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ForegroundAppAction {
+    /// Leave the app running but send it to the background.
+    #[default]
+    Background,
+    /// Close the app outright.
+    Close,
+}
+
+/// Settings for returning to the homescreen after a period of inactivity.
+/// Disabled by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdleReturnSettings {
+    pub timeout: Option<Duration>,
+    pub foreground_app_action: ForegroundAppAction,
+}
+
+/// Drives the shell's idle-to-homescreen behavior: on every input event the
+/// caller should call [`ShellEventLoop::notice_input`]; if no input arrives
+/// before the configured timeout, the loop backgrounds/closes the current
+/// foreground app (per `settings.foreground_app_action`) and emits
+/// [`Navigation::Homescreen`].
+pub struct ShellEventLoop {
+    idle_timer: IdleTimer,
+}
+
+impl ShellEventLoop {
+    pub fn spawn(
+        settings: IdleReturnSettings,
+        app_manager_tx: mpsc::Sender<AppManagerMessage>,
+        foreground_app_id: impl Fn() -> Option<String> + Send + 'static,
+        nav_tx: mpsc::Sender<Navigation>,
+    ) -> Self {
+        let (idle_timer, handle) = IdleTimer::new(settings.timeout);
+        tokio::spawn(Self::watch(
+            handle,
+            settings,
+            app_manager_tx,
+            foreground_app_id,
+            nav_tx,
+        ));
+        Self { idle_timer }
+    }
+
+    /// Reset the idle deadline; call this on any input event.
+    pub fn notice_input(&self) {
+        self.idle_timer.reset();
+    }
+
+    async fn watch(
+        mut handle: IdleTimerHandle,
+        settings: IdleReturnSettings,
+        app_manager_tx: mpsc::Sender<AppManagerMessage>,
+        foreground_app_id: impl Fn() -> Option<String> + Send,
+        nav_tx: mpsc::Sender<Navigation>,
+    ) {
+        loop {
+            handle.wait_for_idle().await;
+
+            if let Some(app_id) = foreground_app_id() {
+                match settings.foreground_app_action {
+                    ForegroundAppAction::Close => {
+                        let _ = app_manager_tx.send(AppManagerMessage::CloseApp { app_id }).await;
+                    }
+                    ForegroundAppAction::Background => {
+                        // Nothing to tell the launcher yet: the app keeps
+                        // running and the shell just stops showing it.
+                        tracing::debug!(%app_id, "idle timeout reached, backgrounding foreground app");
+                    }
+                }
+            }
+
+            if nav_tx.send(Navigation::Homescreen).await.is_err() {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A status icon rendered in a status bar module. Most icons are a single
+/// static frame; some (e.g. a charging battery, a "downloading" spinner)
+/// are an APNG/GIF-style animation made of several frames shown in a loop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatusIcon {
+    Static(PathBuf),
+    Animated {
+        frames: Vec<PathBuf>,
+        frame_duration: Duration,
+    },
+}
+
+impl StatusIcon {
+    pub fn is_animated(&self) -> bool {
+        matches!(self, StatusIcon::Animated { .. })
+    }
+
+    /// The frame to show at `elapsed` time since the icon started playing.
+    /// Static icons always return their single path.
+    pub fn frame_at(&self, elapsed: Duration) -> &PathBuf {
+        match self {
+            StatusIcon::Static(path) => path,
+            StatusIcon::Animated {
+                frames,
+                frame_duration,
+            } => {
+                let frame_duration_ms = frame_duration.as_millis().max(1);
+                let index = (elapsed.as_millis() / frame_duration_ms) as usize % frames.len();
+                &frames[index]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_icon_always_returns_same_frame() {
+        let icon = StatusIcon::Static(PathBuf::from("wifi.svg"));
+        assert_eq!(icon.frame_at(Duration::from_secs(5)), &PathBuf::from("wifi.svg"));
+    }
+
+    #[test]
+    fn animated_icon_cycles_frames() {
+        let icon = StatusIcon::Animated {
+            frames: vec![PathBuf::from("a.png"), PathBuf::from("b.png")],
+            frame_duration: Duration::from_millis(100),
+        };
+        assert_eq!(icon.frame_at(Duration::from_millis(0)), &PathBuf::from("a.png"));
+        assert_eq!(icon.frame_at(Duration::from_millis(100)), &PathBuf::from("b.png"));
+        assert_eq!(icon.frame_at(Duration::from_millis(200)), &PathBuf::from("a.png"));
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Which section of the status bar a module renders in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Section {
+    Left,
+    Center,
+    Right,
+}
+
+/// The configured left/center/right ordering of status bar modules, by
+/// module name (e.g. `"clock"`, `"wifi"`, `"battery"`). Modules not listed
+/// here simply don't render.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StatusBarLayout {
+    #[serde(default)]
+    pub left: Vec<String>,
+    #[serde(default)]
+    pub center: Vec<String>,
+    #[serde(default)]
+    pub right: Vec<String>,
+}
+
+impl StatusBarLayout {
+    pub fn modules_in(&self, section: Section) -> &[String] {
+        match section {
+            Section::Left => &self.left,
+            Section::Center => &self.center,
+            Section::Right => &self.right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modules_render_in_configured_order() {
+        let layout = StatusBarLayout {
+            left: vec!["clock".to_string()],
+            center: vec![],
+            right: vec!["battery".to_string(), "wifi".to_string()],
+        };
+        assert_eq!(layout.modules_in(Section::Left), &["clock".to_string()]);
+        assert_eq!(
+            layout.modules_in(Section::Right),
+            &["battery".to_string(), "wifi".to_string()]
+        );
+    }
+}
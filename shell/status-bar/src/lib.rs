@@ -0,0 +1,17 @@
+pub mod icon;
+pub mod layout;
+pub mod modules;
+pub mod settings;
+pub mod status_bar;
+pub mod title;
+
+pub use clock::{Clock, ClockServiceHandle};
+pub use icon::StatusIcon;
+pub use layout::{Section, StatusBarLayout};
+pub use modules::battery::BatteryModule;
+pub use modules::focused_app::FocusedAppModule;
+pub use modules::notification_count::NotificationCountModule;
+pub use modules::system_usage::{read_system_usage, CpuTimes, SystemUsage};
+pub use settings::StatusBarSettings;
+pub use status_bar::StatusBar;
+pub use title::truncate_with_ellipsis;
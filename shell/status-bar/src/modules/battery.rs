@@ -0,0 +1,66 @@
+use std::time::{Duration, Instant};
+
+use battery::{BatteryEstimator, BatteryReading, CriticalBatteryWarning};
+
+/// Status bar battery module: tracks the latest reading, debounces the
+/// critical-level warning overlay via [`CriticalBatteryWarning`], and
+/// estimates time remaining until empty/full via [`BatteryEstimator`].
+#[derive(Debug)]
+pub struct BatteryModule {
+    reading: Option<BatteryReading>,
+    warning: CriticalBatteryWarning,
+    estimator: BatteryEstimator,
+    time_remaining: Option<Duration>,
+}
+
+impl Default for BatteryModule {
+    fn default() -> Self {
+        Self {
+            reading: None,
+            warning: CriticalBatteryWarning::default(),
+            estimator: BatteryEstimator::new(),
+            time_remaining: None,
+        }
+    }
+}
+
+impl BatteryModule {
+    /// Feeds a new `level`/`status` poll. Returns `true` exactly when the
+    /// critical-level overlay should be shown for this update.
+    pub fn observe(&mut self, reading: BatteryReading, now: Instant) -> bool {
+        self.reading = Some(reading);
+        self.time_remaining = self.estimator.observe(reading, now);
+        self.warning.observe(reading)
+    }
+
+    pub fn reading(&self) -> Option<BatteryReading> {
+        self.reading
+    }
+
+    /// Estimated time until the battery reaches empty (discharging) or
+    /// full (charging), or `None` if there isn't enough data yet.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        self.time_remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossing_below_threshold_triggers_overlay_once() {
+        let mut module = BatteryModule::default();
+        let now = Instant::now();
+        assert!(!module.observe(BatteryReading { level: 50, is_charging: false }, now));
+        assert!(module.observe(BatteryReading { level: 9, is_charging: false }, now));
+        assert!(!module.observe(BatteryReading { level: 8, is_charging: false }, now));
+    }
+
+    #[test]
+    fn time_remaining_is_none_until_a_second_reading_arrives() {
+        let mut module = BatteryModule::default();
+        module.observe(BatteryReading { level: 50, is_charging: false }, Instant::now());
+        assert_eq!(module.time_remaining(), None);
+    }
+}
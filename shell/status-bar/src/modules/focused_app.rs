@@ -0,0 +1,67 @@
+use desktop_entry::{AppTile, DEFAULT_ICON};
+
+use crate::title::truncate_with_ellipsis;
+
+/// Status bar module showing the currently focused window's icon and
+/// title. Pushed in by whatever surface tracks toplevel focus and app
+/// updates (an `AppsUpdated` event upstream of
+/// `AppManagerService::ListRunning`) rather than polled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FocusedAppModule {
+    focused: Option<AppTile>,
+    window_title: Option<String>,
+}
+
+impl FocusedAppModule {
+    pub fn set_focused(&mut self, app: Option<AppTile>, window_title: Option<String>) {
+        self.focused = app;
+        self.window_title = window_title;
+    }
+
+    /// Icon path to render, falling back to the generic executable icon
+    /// when nothing is focused rather than leaving the tile blank.
+    pub fn icon(&self) -> &str {
+        self.focused.as_ref().map(|app| app.icon.as_str()).unwrap_or(DEFAULT_ICON)
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.focused.as_ref().map(|app| app.name.as_str())
+    }
+
+    /// The focused window's title, truncated to `max_len` chars (see
+    /// [`crate::settings::StatusBarSettings::window_title_max_len`]).
+    pub fn title(&self, max_len: usize) -> Option<String> {
+        self.window_title.as_deref().map(|title| truncate_with_ellipsis(title, max_len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile() -> AppTile {
+        AppTile {
+            app_id: "browser".to_string(),
+            name: "Browser".to_string(),
+            icon: "browser.svg".to_string(),
+            window_count: 1,
+        }
+    }
+
+    #[test]
+    fn nothing_focused_falls_back_to_the_default_icon() {
+        let module = FocusedAppModule::default();
+        assert_eq!(module.icon(), DEFAULT_ICON);
+        assert_eq!(module.name(), None);
+        assert_eq!(module.title(32), None);
+    }
+
+    #[test]
+    fn set_focused_updates_the_rendered_icon_name_and_title() {
+        let mut module = FocusedAppModule::default();
+        module.set_focused(Some(tile()), Some("a very long window title to truncate".to_string()));
+        assert_eq!(module.icon(), "browser.svg");
+        assert_eq!(module.name(), Some("Browser"));
+        assert_eq!(module.title(10), Some("a very lon…".to_string()));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod battery;
+pub mod focused_app;
+pub mod notification_count;
+pub mod system_usage;
@@ -0,0 +1,43 @@
+/// Status bar module showing the number of unread notifications as a
+/// small badge. The count is pushed in from the notification daemon rather
+/// than polled, so this module is intentionally dumb state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NotificationCountModule {
+    count: u32,
+}
+
+impl NotificationCountModule {
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count;
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Badge text, or `None` when there's nothing to show.
+    pub fn badge_text(&self) -> Option<String> {
+        match self.count {
+            0 => None,
+            1..=99 => Some(self.count.to_string()),
+            _ => Some("99+".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_count_has_no_badge() {
+        assert_eq!(NotificationCountModule::default().badge_text(), None);
+    }
+
+    #[test]
+    fn large_count_is_capped() {
+        let mut module = NotificationCountModule::default();
+        module.set_count(150);
+        assert_eq!(module.badge_text(), Some("99+".to_string()));
+    }
+}
@@ -0,0 +1,105 @@
+use std::fs;
+
+/// Snapshot of CPU/memory usage shown in the status bar's system usage
+/// module.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SystemUsage {
+    pub cpu_percent: f32,
+    pub mem_percent: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuTimes {
+    idle: u64,
+    total: u64,
+}
+
+/// Polls `/proc/stat` and `/proc/meminfo` for CPU/memory usage. CPU usage is
+/// computed as the delta between two samples, so the caller should hold on
+/// to `previous` (from the last tick) and pass it back in.
+pub fn read_system_usage(previous: Option<CpuTimes>) -> (SystemUsage, CpuTimes) {
+    let cpu_times = read_cpu_times().unwrap_or_default();
+    let cpu_percent = previous
+        .map(|prev| cpu_percent_delta(prev, cpu_times))
+        .unwrap_or(0.0);
+    let mem_percent = read_mem_percent().unwrap_or(0.0);
+    (
+        SystemUsage {
+            cpu_percent,
+            mem_percent,
+        },
+        cpu_times,
+    )
+}
+
+fn cpu_percent_delta(prev: CpuTimes, current: CpuTimes) -> f32 {
+    let total_delta = current.total.saturating_sub(prev.total);
+    if total_delta == 0 {
+        return 0.0;
+    }
+    let idle_delta = current.idle.saturating_sub(prev.idle);
+    (1.0 - idle_delta as f32 / total_delta as f32) * 100.0
+}
+
+fn read_cpu_times() -> Option<CpuTimes> {
+    let contents = fs::read_to_string("/proc/stat").ok()?;
+    let line = contents.lines().next()?;
+    let fields: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|f| f.parse().ok())
+        .collect();
+    let total: u64 = fields.iter().sum();
+    let idle = *fields.get(3)? + fields.get(4).copied().unwrap_or(0);
+    Some(CpuTimes { idle, total })
+}
+
+fn read_mem_percent() -> Option<f32> {
+    let contents = fs::read_to_string("/proc/meminfo").ok()?;
+    let mut total = None;
+    let mut available = None;
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("MemTotal:") {
+            total = parse_kb(value);
+        } else if let Some(value) = line.strip_prefix("MemAvailable:") {
+            available = parse_kb(value);
+        }
+    }
+    let (total, available) = (total?, available?);
+    if total == 0.0 {
+        return None;
+    }
+    Some((1.0 - available / total) * 100.0)
+}
+
+fn parse_kb(value: &str) -> Option<f32> {
+    value.trim().trim_end_matches(" kB").trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpu_percent_delta_is_zero_when_idle_matches_total() {
+        let prev = CpuTimes { idle: 0, total: 0 };
+        let current = CpuTimes {
+            idle: 100,
+            total: 100,
+        };
+        assert_eq!(cpu_percent_delta(prev, current), 0.0);
+    }
+
+    #[test]
+    fn cpu_percent_delta_is_nonzero_under_load() {
+        let prev = CpuTimes {
+            idle: 50,
+            total: 100,
+        };
+        let current = CpuTimes {
+            idle: 60,
+            total: 200,
+        };
+        assert_eq!(cpu_percent_delta(prev, current), 90.0);
+    }
+}
@@ -0,0 +1,49 @@
+use clock::Clock;
+use serde::{Deserialize, Serialize};
+
+use crate::layout::StatusBarLayout;
+
+/// Status bar settings loaded from the shell's `settings.yml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusBarSettings {
+    #[serde(default = "default_scale_factor")]
+    pub scale_factor: f32,
+    #[serde(default)]
+    pub layout: StatusBarLayout,
+    /// Passed to `ClockServiceHandle::run`/`set_format` for the clock
+    /// module.
+    #[serde(default)]
+    pub clock_format: Clock,
+    /// IANA timezone name for the clock module, e.g. `"America/New_York"`.
+    /// Falls back to UTC via [`clock::parse_timezone`] if unrecognized.
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+    /// Max chars (not bytes) of a focused window's title to show before
+    /// truncating with an ellipsis; see [`crate::title::truncate_with_ellipsis`].
+    #[serde(default = "default_window_title_max_len")]
+    pub window_title_max_len: usize,
+}
+
+impl Default for StatusBarSettings {
+    fn default() -> Self {
+        Self {
+            scale_factor: default_scale_factor(),
+            layout: StatusBarLayout::default(),
+            clock_format: Clock::default(),
+            timezone: default_timezone(),
+            window_title_max_len: default_window_title_max_len(),
+        }
+    }
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
+fn default_window_title_max_len() -> usize {
+    32
+}
+
+fn default_scale_factor() -> f32 {
+    1.0
+}
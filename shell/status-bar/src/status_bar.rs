@@ -0,0 +1,34 @@
+use crate::settings::StatusBarSettings;
+
+/// The status bar surface itself: owns the settings that size every module
+/// it renders.
+pub struct StatusBar {
+    pub settings: StatusBarSettings,
+}
+
+impl StatusBar {
+    pub fn new(settings: StatusBarSettings) -> Self {
+        Self { settings }
+    }
+
+    /// Scale a logical pixel size by the configured scale factor. Previously
+    /// this multiplied by a hardcoded `1.0`, which meant `scale_factor` in
+    /// `settings.yml` had no effect on the status bar.
+    pub fn scale(&self, logical_px: f32) -> f32 {
+        logical_px * self.settings.scale_factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_uses_configured_factor() {
+        let bar = StatusBar::new(StatusBarSettings {
+            scale_factor: 2.0,
+            ..Default::default()
+        });
+        assert_eq!(bar.scale(10.0), 20.0);
+    }
+}
@@ -0,0 +1,34 @@
+/// Truncates `text` to `max_len` chars (not bytes) and appends an
+/// ellipsis, so the status bar never slices in the middle of a multi-byte
+/// UTF-8 codepoint when a window title is too long to fit.
+pub fn truncate_with_ellipsis(text: &str, max_len: usize) -> String {
+    if text.chars().count() <= max_len {
+        return text.to_string();
+    }
+
+    let truncated: String = text.chars().take(max_len).collect();
+    format!("{truncated}…")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_title_is_unchanged() {
+        assert_eq!(truncate_with_ellipsis("Terminal", 32), "Terminal");
+    }
+
+    #[test]
+    fn long_title_is_truncated_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("A Very Long Window Title Indeed", 10), "A Very Lon…");
+    }
+
+    #[test]
+    fn multibyte_title_does_not_panic() {
+        let title = "日本語のウィンドウタイトルがとても長い場合";
+        let truncated = truncate_with_ellipsis(title, 10);
+        assert!(truncated.ends_with('…'));
+        assert_eq!(truncated.chars().count(), 11);
+    }
+}